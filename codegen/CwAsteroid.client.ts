@@ -6,26 +6,71 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwAsteroid.types";
+import { Uint128, Binary, Addr, InstantiateMsg, RatingConfig, ScoreTier, ExecuteMsg, ExecuteMsgForAsteroidConfig, AbsencePolicy, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForAsteroidConfig, AsteroidConfig, Handicap, TelegramAttestation, RelayPayload, QueryMsg, Config, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForAsteroidConfig, GameRound, ArrayOfHighScore, HighScore, ArrayOfTupleOfAddrAndStringAndUint128, NullableHighScore, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwAsteroid.types";
 export interface CwAsteroidReadOnlyInterface {
   contractAddress: string;
+  getConfig: () => Promise<Config>;
+  getHighScores: ({
+    limit
+  }: {
+    limit?: number;
+  }) => Promise<ArrayOfHighScore>;
+  getPersonalBest: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<NullableHighScore>;
   getGame: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<Game>;
-  getLeaderboard: () => Promise<Leaderboard>;
+  }) => Promise<GameForAsteroidConfig>;
+  getLeaderboard: () => Promise<ArrayOfTupleOfAddrAndStringAndUint128>;
   getCurrentRound: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<GameRound>;
+  }) => Promise<CurrentRoundResponse>;
   getGameStatus: ({
     gameId
   }: {
     gameId: number;
   }) => Promise<GameStatus>;
   getGamesCount: () => Promise<Uint64>;
+  getPlayerStats: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<PlayerStats>;
+  getPlayerStatsByTelegramId: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }) => Promise<PlayerStats>;
+  getDelegation: ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }) => Promise<NullableDelegation>;
+  getRelayNonce: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<Uint64>;
+  getRating: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<RatingResponse>;
+  getTopRatings: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }) => Promise<ArrayOfRatingResponse>;
 }
 export class CwAsteroidQueryClient implements CwAsteroidReadOnlyInterface {
   client: CosmWasmClient;
@@ -33,24 +78,60 @@ export class CwAsteroidQueryClient implements CwAsteroidReadOnlyInterface {
   constructor(client: CosmWasmClient, contractAddress: string) {
     this.client = client;
     this.contractAddress = contractAddress;
+    this.getConfig = this.getConfig.bind(this);
+    this.getHighScores = this.getHighScores.bind(this);
+    this.getPersonalBest = this.getPersonalBest.bind(this);
     this.getGame = this.getGame.bind(this);
     this.getLeaderboard = this.getLeaderboard.bind(this);
     this.getCurrentRound = this.getCurrentRound.bind(this);
     this.getGameStatus = this.getGameStatus.bind(this);
     this.getGamesCount = this.getGamesCount.bind(this);
+    this.getPlayerStats = this.getPlayerStats.bind(this);
+    this.getPlayerStatsByTelegramId = this.getPlayerStatsByTelegramId.bind(this);
+    this.getDelegation = this.getDelegation.bind(this);
+    this.getRelayNonce = this.getRelayNonce.bind(this);
+    this.getRating = this.getRating.bind(this);
+    this.getTopRatings = this.getTopRatings.bind(this);
   }
+  getConfig = async (): Promise<Config> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_config: {}
+    });
+  };
+  getHighScores = async ({
+    limit
+  }: {
+    limit?: number;
+  }): Promise<ArrayOfHighScore> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_high_scores: {
+        limit
+      }
+    });
+  };
+  getPersonalBest = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<NullableHighScore> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_personal_best: {
+        player
+      }
+    });
+  };
   getGame = async ({
     gameId
   }: {
     gameId: number;
-  }): Promise<Game> => {
+  }): Promise<GameForAsteroidConfig> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_game: {
         game_id: gameId
       }
     });
   };
-  getLeaderboard = async (): Promise<Leaderboard> => {
+  getLeaderboard = async (): Promise<ArrayOfTupleOfAddrAndStringAndUint128> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_leaderboard: {}
     });
@@ -59,7 +140,7 @@ export class CwAsteroidQueryClient implements CwAsteroidReadOnlyInterface {
     gameId
   }: {
     gameId: number;
-  }): Promise<GameRound> => {
+  }): Promise<CurrentRoundResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_current_round: {
         game_id: gameId
@@ -82,11 +163,83 @@ export class CwAsteroidQueryClient implements CwAsteroidReadOnlyInterface {
       get_games_count: {}
     });
   };
+  getPlayerStats = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats: {
+        player
+      }
+    });
+  };
+  getPlayerStatsByTelegramId = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats_by_telegram_id: {
+        telegram_id: telegramId
+      }
+    });
+  };
+  getDelegation = async ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }): Promise<NullableDelegation> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegation: {
+        delegate,
+        player
+      }
+    });
+  };
+  getRelayNonce = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_relay_nonce: {
+        player
+      }
+    });
+  };
+  getRating = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<RatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_rating: {
+        player
+      }
+    });
+  };
+  getTopRatings = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }): Promise<ArrayOfRatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_top_ratings: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface CwAsteroidInterface extends CwAsteroidReadOnlyInterface {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  lifecycle: (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class CwAsteroidClient extends CwAsteroidQueryClient implements CwAsteroidInterface {
   client: SigningCosmWasmClient;
@@ -99,9 +252,9 @@ export class CwAsteroidClient extends CwAsteroidQueryClient implements CwAsteroi
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = async (executeMsg: ExecuteMsg, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+  lifecycle = async (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      lifecycle: executeMsg
+      lifecycle: executeMsgForAsteroidConfig
     }, fee, memo, _funds);
   };
 }
\ No newline at end of file
@@ -8,11 +8,11 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwAsteroid.types";
+import { Uint128, Binary, Addr, InstantiateMsg, RatingConfig, ScoreTier, ExecuteMsg, ExecuteMsgForAsteroidConfig, AbsencePolicy, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForAsteroidConfig, AsteroidConfig, Handicap, TelegramAttestation, RelayPayload, QueryMsg, Config, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForAsteroidConfig, GameRound, ArrayOfHighScore, HighScore, ArrayOfTupleOfAddrAndStringAndUint128, NullableHighScore, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwAsteroid.types";
 export interface CwAsteroidMsg {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  lifecycle: (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwAsteroidMsgComposer implements CwAsteroidMsg {
   sender: string;
@@ -22,14 +22,14 @@ export class CwAsteroidMsgComposer implements CwAsteroidMsg {
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = (executeMsg: ExecuteMsg, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  lifecycle = (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          lifecycle: executeMsg
+          lifecycle: executeMsgForAsteroidConfig
         })),
         funds: _funds
       })
@@ -8,11 +8,11 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwCooperationDilemma.types";
+import { Binary, Addr, Uint128, InstantiateMsg, Payoffs, RatingConfig, ExecuteMsg, ExecuteMsgForDilemmaConfig, AbsencePolicy, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForDilemmaConfig, DilemmaConfig, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForDilemmaConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwCooperationDilemma.types";
 export interface CwCooperationDilemmaMsg {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  lifecycle: (executeMsgForDilemmaConfig: ExecuteMsgForDilemmaConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwCooperationDilemmaMsgComposer implements CwCooperationDilemmaMsg {
   sender: string;
@@ -22,14 +22,14 @@ export class CwCooperationDilemmaMsgComposer implements CwCooperationDilemmaMsg
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = (executeMsg: ExecuteMsg, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  lifecycle = (executeMsgForDilemmaConfig: ExecuteMsgForDilemmaConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          lifecycle: executeMsg
+          lifecycle: executeMsgForDilemmaConfig
         })),
         funds: _funds
       })
@@ -6,10 +6,10 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, QueryMsg, Uint64 } from "./CwCounter.types";
+import { InstantiateMsg, ExecuteMsg, QueryMsg, GetCountResponse } from "./CwCounter.types";
 export interface CwCounterReadOnlyInterface {
   contractAddress: string;
-  getCount: () => Promise<Uint64>;
+  getCount: () => Promise<GetCountResponse>;
 }
 export class CwCounterQueryClient implements CwCounterReadOnlyInterface {
   client: CosmWasmClient;
@@ -19,7 +19,7 @@ export class CwCounterQueryClient implements CwCounterReadOnlyInterface {
     this.contractAddress = contractAddress;
     this.getCount = this.getCount.bind(this);
   }
-  getCount = async (): Promise<Uint64> => {
+  getCount = async (): Promise<GetCountResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_count: {}
     });
@@ -29,6 +29,11 @@ export interface CwCounterInterface extends CwCounterReadOnlyInterface {
   contractAddress: string;
   sender: string;
   increment: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  reset: ({
+    count
+  }: {
+    count: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class CwCounterClient extends CwCounterQueryClient implements CwCounterInterface {
   client: SigningCosmWasmClient;
@@ -40,10 +45,22 @@ export class CwCounterClient extends CwCounterQueryClient implements CwCounterIn
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.increment = this.increment.bind(this);
+    this.reset = this.reset.bind(this);
   }
   increment = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       increment: {}
     }, fee, memo, _funds);
   };
+  reset = async ({
+    count
+  }: {
+    count: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reset: {
+        count
+      }
+    }, fee, memo, _funds);
+  };
 }
\ No newline at end of file
@@ -8,11 +8,16 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { InstantiateMsg, ExecuteMsg, QueryMsg, Uint64 } from "./CwCounter.types";
+import { InstantiateMsg, ExecuteMsg, QueryMsg, GetCountResponse } from "./CwCounter.types";
 export interface CwCounterMsg {
   contractAddress: string;
   sender: string;
   increment: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  reset: ({
+    count
+  }: {
+    count: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwCounterMsgComposer implements CwCounterMsg {
   sender: string;
@@ -21,6 +26,7 @@ export class CwCounterMsgComposer implements CwCounterMsg {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.increment = this.increment.bind(this);
+    this.reset = this.reset.bind(this);
   }
   increment = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -35,4 +41,23 @@ export class CwCounterMsgComposer implements CwCounterMsg {
       })
     };
   };
+  reset = ({
+    count
+  }: {
+    count: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reset: {
+            count
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
 }
\ No newline at end of file
@@ -7,8 +7,14 @@
 export interface InstantiateMsg {}
 export type ExecuteMsg = {
   increment: {};
+} | {
+  reset: {
+    count: number;
+  };
 };
 export type QueryMsg = {
   get_count: {};
 };
-export type Uint64 = number;
\ No newline at end of file
+export interface GetCountResponse {
+  count: number;
+}
\ No newline at end of file
@@ -8,14 +8,14 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, ArrayOfTupleOfAddrAndStringAndUint128 } from "./CwGameLifecycle.types";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, AbsencePolicy, Uint128, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForEmpty, Empty, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForEmpty, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwGameLifecycle.types";
 export interface CwGameLifecycleMsg {
   contractAddress: string;
   sender: string;
   createGame: ({
     config
   }: {
-    config: GameConfig;
+    config: GameConfigForEmpty;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   startGame: ({
     gameId
@@ -23,9 +23,11 @@ export interface CwGameLifecycleMsg {
     gameId: number;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   joinGame: ({
+    attestation,
     gameId,
     telegramId
   }: {
+    attestation?: TelegramAttestation;
     gameId: number;
     telegramId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
@@ -38,7 +40,7 @@ export interface CwGameLifecycleMsg {
     gameId: number;
     value: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
-  commitRoundAsAdmin: ({
+  commitRoundAsDelegate: ({
     amount,
     gameId,
     player,
@@ -58,6 +60,54 @@ export interface CwGameLifecycleMsg {
     nonce: number;
     value: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revealRoundAsDelegate: ({
+    gameId,
+    nonce,
+    player,
+    value
+  }: {
+    gameId: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  playTurn: ({
+    gameId,
+    value
+  }: {
+    gameId: number;
+    value: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  grantDelegate: ({
+    delegate,
+    expiresAt,
+    gameId,
+    maxActions
+  }: {
+    delegate: Addr;
+    expiresAt: Expiration;
+    gameId?: number;
+    maxActions: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeDelegate: ({
+    delegate
+  }: {
+    delegate: Addr;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  relaySigned: ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  advanceRound: ({
+    gameId
+  }: {
+    gameId: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   endGame: ({
     gameId
   }: {
@@ -74,14 +124,20 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
     this.startGame = this.startGame.bind(this);
     this.joinGame = this.joinGame.bind(this);
     this.commitRound = this.commitRound.bind(this);
-    this.commitRoundAsAdmin = this.commitRoundAsAdmin.bind(this);
+    this.commitRoundAsDelegate = this.commitRoundAsDelegate.bind(this);
     this.revealRound = this.revealRound.bind(this);
+    this.revealRoundAsDelegate = this.revealRoundAsDelegate.bind(this);
+    this.playTurn = this.playTurn.bind(this);
+    this.grantDelegate = this.grantDelegate.bind(this);
+    this.revokeDelegate = this.revokeDelegate.bind(this);
+    this.relaySigned = this.relaySigned.bind(this);
+    this.advanceRound = this.advanceRound.bind(this);
     this.endGame = this.endGame.bind(this);
   }
   createGame = ({
     config
   }: {
-    config: GameConfig;
+    config: GameConfigForEmpty;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -117,9 +173,11 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
     };
   };
   joinGame = ({
+    attestation,
     gameId,
     telegramId
   }: {
+    attestation?: TelegramAttestation;
     gameId: number;
     telegramId: string;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
@@ -130,6 +188,7 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           join_game: {
+            attestation,
             game_id: gameId,
             telegram_id: telegramId
           }
@@ -163,7 +222,7 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
       })
     };
   };
-  commitRoundAsAdmin = ({
+  commitRoundAsDelegate = ({
     amount,
     gameId,
     player,
@@ -180,7 +239,7 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          commit_round_as_admin: {
+          commit_round_as_delegate: {
             amount,
             game_id: gameId,
             player,
@@ -216,6 +275,147 @@ export class CwGameLifecycleMsgComposer implements CwGameLifecycleMsg {
       })
     };
   };
+  revealRoundAsDelegate = ({
+    gameId,
+    nonce,
+    player,
+    value
+  }: {
+    gameId: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reveal_round_as_delegate: {
+            game_id: gameId,
+            nonce,
+            player,
+            value
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playTurn = ({
+    gameId,
+    value
+  }: {
+    gameId: number;
+    value: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          play_turn: {
+            game_id: gameId,
+            value
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  grantDelegate = ({
+    delegate,
+    expiresAt,
+    gameId,
+    maxActions
+  }: {
+    delegate: Addr;
+    expiresAt: Expiration;
+    gameId?: number;
+    maxActions: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          grant_delegate: {
+            delegate,
+            expires_at: expiresAt,
+            game_id: gameId,
+            max_actions: maxActions
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  revokeDelegate = ({
+    delegate
+  }: {
+    delegate: Addr;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_delegate: {
+            delegate
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  relaySigned = ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          relay_signed: {
+            payload,
+            pubkey,
+            signature
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  advanceRound = ({
+    gameId
+  }: {
+    gameId: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          advance_round: {
+            game_id: gameId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
   endGame = ({
     gameId
   }: {
@@ -0,0 +1,154 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
+import { Coin, StdFee } from "@cosmjs/amino";
+import { Addr, InstantiateMsg, ExecuteMsg, QueryMsg, NullableAddr, ArrayOfAddr, NullableString } from "./CwIdentity.types";
+export interface CwIdentityReadOnlyInterface {
+  contractAddress: string;
+  getAddress: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }) => Promise<NullableAddr>;
+  getTelegramId: ({
+    address
+  }: {
+    address: Addr;
+  }) => Promise<NullableString>;
+  getAdmins: () => Promise<ArrayOfAddr>;
+}
+export class CwIdentityQueryClient implements CwIdentityReadOnlyInterface {
+  client: CosmWasmClient;
+  contractAddress: string;
+  constructor(client: CosmWasmClient, contractAddress: string) {
+    this.client = client;
+    this.contractAddress = contractAddress;
+    this.getAddress = this.getAddress.bind(this);
+    this.getTelegramId = this.getTelegramId.bind(this);
+    this.getAdmins = this.getAdmins.bind(this);
+  }
+  getAddress = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }): Promise<NullableAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_address: {
+        telegram_id: telegramId
+      }
+    });
+  };
+  getTelegramId = async ({
+    address
+  }: {
+    address: Addr;
+  }): Promise<NullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_telegram_id: {
+        address
+      }
+    });
+  };
+  getAdmins = async (): Promise<ArrayOfAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_admins: {}
+    });
+  };
+}
+export interface CwIdentityInterface extends CwIdentityReadOnlyInterface {
+  contractAddress: string;
+  sender: string;
+  bind: ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  rebind: ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  unbind: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  updateAdmins: ({
+    admins
+  }: {
+    admins: Addr[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+}
+export class CwIdentityClient extends CwIdentityQueryClient implements CwIdentityInterface {
+  client: SigningCosmWasmClient;
+  sender: string;
+  contractAddress: string;
+  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
+    super(client, contractAddress);
+    this.client = client;
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.bind = this.bind.bind(this);
+    this.rebind = this.rebind.bind(this);
+    this.unbind = this.unbind.bind(this);
+    this.updateAdmins = this.updateAdmins.bind(this);
+  }
+  bind = async ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      bind: {
+        address,
+        telegram_id: telegramId
+      }
+    }, fee, memo, _funds);
+  };
+  rebind = async ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rebind: {
+        address,
+        telegram_id: telegramId
+      }
+    }, fee, memo, _funds);
+  };
+  unbind = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unbind: {
+        telegram_id: telegramId
+      }
+    }, fee, memo, _funds);
+  };
+  updateAdmins = async ({
+    admins
+  }: {
+    admins: Addr[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_admins: {
+        admins
+      }
+    }, fee, memo, _funds);
+  };
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+import { Coin } from "@cosmjs/amino";
+import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
+import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
+import { toUtf8 } from "@cosmjs/encoding";
+import { Addr, InstantiateMsg, ExecuteMsg, QueryMsg, NullableAddr, ArrayOfAddr, NullableString } from "./CwIdentity.types";
+export interface CwIdentityMsg {
+  contractAddress: string;
+  sender: string;
+  bind: ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rebind: ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  unbind: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateAdmins: ({
+    admins
+  }: {
+    admins: Addr[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+}
+export class CwIdentityMsgComposer implements CwIdentityMsg {
+  sender: string;
+  contractAddress: string;
+  constructor(sender: string, contractAddress: string) {
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.bind = this.bind.bind(this);
+    this.rebind = this.rebind.bind(this);
+    this.unbind = this.unbind.bind(this);
+    this.updateAdmins = this.updateAdmins.bind(this);
+  }
+  bind = ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          bind: {
+            address,
+            telegram_id: telegramId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  rebind = ({
+    address,
+    telegramId
+  }: {
+    address: Addr;
+    telegramId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          rebind: {
+            address,
+            telegram_id: telegramId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  unbind = ({
+    telegramId
+  }: {
+    telegramId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unbind: {
+            telegram_id: telegramId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  updateAdmins = ({
+    admins
+  }: {
+    admins: Addr[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_admins: {
+            admins
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+}
\ No newline at end of file
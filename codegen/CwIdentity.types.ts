@@ -0,0 +1,43 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+export type Addr = string;
+export interface InstantiateMsg {
+  admins: Addr[];
+}
+export type ExecuteMsg = {
+  bind: {
+    address: Addr;
+    telegram_id: string;
+  };
+} | {
+  rebind: {
+    address: Addr;
+    telegram_id: string;
+  };
+} | {
+  unbind: {
+    telegram_id: string;
+  };
+} | {
+  update_admins: {
+    admins: Addr[];
+  };
+};
+export type QueryMsg = {
+  get_address: {
+    telegram_id: string;
+  };
+} | {
+  get_telegram_id: {
+    address: Addr;
+  };
+} | {
+  get_admins: {};
+};
+export type NullableAddr = Addr | null;
+export type ArrayOfAddr = Addr[];
+export type NullableString = string | null;
\ No newline at end of file
@@ -0,0 +1,38 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+import { Coin } from "@cosmjs/amino";
+import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
+import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
+import { toUtf8 } from "@cosmjs/encoding";
+import { Binary, Addr, Uint128, InstantiateMsg, Matrix, Payoff, RatingConfig, ExecuteMsg, ExecuteMsgForEmpty, AbsencePolicy, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForEmpty, Empty, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForEmpty, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwMatrixGame.types";
+export interface CwMatrixGameMsg {
+  contractAddress: string;
+  sender: string;
+  lifecycle: (executeMsgForEmpty: ExecuteMsgForEmpty, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+}
+export class CwMatrixGameMsgComposer implements CwMatrixGameMsg {
+  sender: string;
+  contractAddress: string;
+  constructor(sender: string, contractAddress: string) {
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lifecycle = this.lifecycle.bind(this);
+  }
+  lifecycle = (executeMsgForEmpty: ExecuteMsgForEmpty, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          lifecycle: executeMsgForEmpty
+        })),
+        funds: _funds
+      })
+    };
+  };
+}
\ No newline at end of file
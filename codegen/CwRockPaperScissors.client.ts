@@ -6,26 +6,65 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwRockPaperScissors.types";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForRockPaperScissorsConfig, AbsencePolicy, Uint128, Duration, RockPaperScissorsVariant, Expiration, Timestamp, Uint64, RelayAction, GameConfigForRockPaperScissorsConfig, RockPaperScissorsConfig, TelegramAttestation, RelayPayload, QueryMsg, ArrayOfString, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForRockPaperScissorsConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwRockPaperScissors.types";
 export interface CwRockPaperScissorsReadOnlyInterface {
   contractAddress: string;
+  getChoices: ({
+    gameId
+  }: {
+    gameId: number;
+  }) => Promise<ArrayOfString>;
   getGame: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<Game>;
-  getLeaderboard: () => Promise<Leaderboard>;
+  }) => Promise<GameForRockPaperScissorsConfig>;
+  getLeaderboard: () => Promise<ArrayOfTupleOfAddrAndStringAndUint128>;
   getCurrentRound: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<GameRound>;
+  }) => Promise<CurrentRoundResponse>;
   getGameStatus: ({
     gameId
   }: {
     gameId: number;
   }) => Promise<GameStatus>;
   getGamesCount: () => Promise<Uint64>;
+  getPlayerStats: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<PlayerStats>;
+  getPlayerStatsByTelegramId: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }) => Promise<PlayerStats>;
+  getDelegation: ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }) => Promise<NullableDelegation>;
+  getRelayNonce: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<Uint64>;
+  getRating: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<RatingResponse>;
+  getTopRatings: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }) => Promise<ArrayOfRatingResponse>;
 }
 export class CwRockPaperScissorsQueryClient implements CwRockPaperScissorsReadOnlyInterface {
   client: CosmWasmClient;
@@ -33,24 +72,42 @@ export class CwRockPaperScissorsQueryClient implements CwRockPaperScissorsReadOn
   constructor(client: CosmWasmClient, contractAddress: string) {
     this.client = client;
     this.contractAddress = contractAddress;
+    this.getChoices = this.getChoices.bind(this);
     this.getGame = this.getGame.bind(this);
     this.getLeaderboard = this.getLeaderboard.bind(this);
     this.getCurrentRound = this.getCurrentRound.bind(this);
     this.getGameStatus = this.getGameStatus.bind(this);
     this.getGamesCount = this.getGamesCount.bind(this);
+    this.getPlayerStats = this.getPlayerStats.bind(this);
+    this.getPlayerStatsByTelegramId = this.getPlayerStatsByTelegramId.bind(this);
+    this.getDelegation = this.getDelegation.bind(this);
+    this.getRelayNonce = this.getRelayNonce.bind(this);
+    this.getRating = this.getRating.bind(this);
+    this.getTopRatings = this.getTopRatings.bind(this);
   }
+  getChoices = async ({
+    gameId
+  }: {
+    gameId: number;
+  }): Promise<ArrayOfString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_choices: {
+        game_id: gameId
+      }
+    });
+  };
   getGame = async ({
     gameId
   }: {
     gameId: number;
-  }): Promise<Game> => {
+  }): Promise<GameForRockPaperScissorsConfig> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_game: {
         game_id: gameId
       }
     });
   };
-  getLeaderboard = async (): Promise<Leaderboard> => {
+  getLeaderboard = async (): Promise<ArrayOfTupleOfAddrAndStringAndUint128> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_leaderboard: {}
     });
@@ -59,7 +116,7 @@ export class CwRockPaperScissorsQueryClient implements CwRockPaperScissorsReadOn
     gameId
   }: {
     gameId: number;
-  }): Promise<GameRound> => {
+  }): Promise<CurrentRoundResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_current_round: {
         game_id: gameId
@@ -82,11 +139,83 @@ export class CwRockPaperScissorsQueryClient implements CwRockPaperScissorsReadOn
       get_games_count: {}
     });
   };
+  getPlayerStats = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats: {
+        player
+      }
+    });
+  };
+  getPlayerStatsByTelegramId = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats_by_telegram_id: {
+        telegram_id: telegramId
+      }
+    });
+  };
+  getDelegation = async ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }): Promise<NullableDelegation> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegation: {
+        delegate,
+        player
+      }
+    });
+  };
+  getRelayNonce = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_relay_nonce: {
+        player
+      }
+    });
+  };
+  getRating = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<RatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_rating: {
+        player
+      }
+    });
+  };
+  getTopRatings = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }): Promise<ArrayOfRatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_top_ratings: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface CwRockPaperScissorsInterface extends CwRockPaperScissorsReadOnlyInterface {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  lifecycle: (executeMsgForRockPaperScissorsConfig: ExecuteMsgForRockPaperScissorsConfig, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class CwRockPaperScissorsClient extends CwRockPaperScissorsQueryClient implements CwRockPaperScissorsInterface {
   client: SigningCosmWasmClient;
@@ -99,9 +228,9 @@ export class CwRockPaperScissorsClient extends CwRockPaperScissorsQueryClient im
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = async (executeMsg: ExecuteMsg, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+  lifecycle = async (executeMsgForRockPaperScissorsConfig: ExecuteMsgForRockPaperScissorsConfig, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      lifecycle: executeMsg
+      lifecycle: executeMsgForRockPaperScissorsConfig
     }, fee, memo, _funds);
   };
 }
\ No newline at end of file
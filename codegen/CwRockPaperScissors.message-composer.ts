@@ -8,11 +8,11 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwRockPaperScissors.types";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForRockPaperScissorsConfig, AbsencePolicy, Uint128, Duration, RockPaperScissorsVariant, Expiration, Timestamp, Uint64, RelayAction, GameConfigForRockPaperScissorsConfig, RockPaperScissorsConfig, TelegramAttestation, RelayPayload, QueryMsg, ArrayOfString, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForRockPaperScissorsConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwRockPaperScissors.types";
 export interface CwRockPaperScissorsMsg {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  lifecycle: (executeMsgForRockPaperScissorsConfig: ExecuteMsgForRockPaperScissorsConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwRockPaperScissorsMsgComposer implements CwRockPaperScissorsMsg {
   sender: string;
@@ -22,14 +22,14 @@ export class CwRockPaperScissorsMsgComposer implements CwRockPaperScissorsMsg {
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = (executeMsg: ExecuteMsg, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  lifecycle = (executeMsgForRockPaperScissorsConfig: ExecuteMsgForRockPaperScissorsConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          lifecycle: executeMsg
+          lifecycle: executeMsgForRockPaperScissorsConfig
         })),
         funds: _funds
       })
@@ -8,11 +8,11 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, ExecuteMsg1, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, Leaderboard } from "./CwTradeGains.types";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForTradeConfig, AbsencePolicy, Uint128, Duration, BargainingMode, Decimal, OverflowRule, Expiration, Timestamp, Uint64, RelayAction, GameConfigForTradeConfig, TradeConfig, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForTradeConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, RatingResponse, ArrayOfRatingResponse } from "./CwTradeGains.types";
 export interface CwTradeGainsMsg {
   contractAddress: string;
   sender: string;
-  lifecycle: (executeMsg: ExecuteMsg, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  lifecycle: (executeMsgForTradeConfig: ExecuteMsgForTradeConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwTradeGainsMsgComposer implements CwTradeGainsMsg {
   sender: string;
@@ -22,14 +22,14 @@ export class CwTradeGainsMsgComposer implements CwTradeGainsMsg {
     this.contractAddress = contractAddress;
     this.lifecycle = this.lifecycle.bind(this);
   }
-  lifecycle = (executeMsg: ExecuteMsg, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  lifecycle = (executeMsgForTradeConfig: ExecuteMsgForTradeConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          lifecycle: executeMsg
+          lifecycle: executeMsgForTradeConfig
         })),
         funds: _funds
       })
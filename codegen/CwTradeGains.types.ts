@@ -4,18 +4,28 @@
 * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
 */
 
+export type Binary = string;
 export type Addr = string;
 export interface InstantiateMsg {
+  attestation_pubkey?: Binary | null;
   base_url: string;
+  identity_contract?: Addr | null;
   image_url: string;
+  rating_config?: RatingConfig | null;
   token_contract: Addr;
 }
+export interface RatingConfig {
+  initial_rating: number;
+  k_factor: number;
+  provisional_games: number;
+  provisional_k_factor: number;
+}
 export type ExecuteMsg = {
-  lifecycle: ExecuteMsg1;
+  lifecycle: ExecuteMsgForTradeConfig;
 };
-export type ExecuteMsg1 = {
+export type ExecuteMsgForTradeConfig = {
   create_game: {
-    config: GameConfig;
+    config: GameConfigForTradeConfig;
   };
 } | {
   start_game: {
@@ -23,6 +33,7 @@ export type ExecuteMsg1 = {
   };
 } | {
   join_game: {
+    attestation?: TelegramAttestation | null;
     game_id: number;
     telegram_id: string;
   };
@@ -33,7 +44,7 @@ export type ExecuteMsg1 = {
     value: string;
   };
 } | {
-  commit_round_as_admin: {
+  commit_round_as_delegate: {
     amount?: Uint128 | null;
     game_id: number;
     player: Addr;
@@ -45,24 +56,120 @@ export type ExecuteMsg1 = {
     nonce: number;
     value: string;
   };
+} | {
+  reveal_round_as_delegate: {
+    game_id: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  };
+} | {
+  play_turn: {
+    game_id: number;
+    value: string;
+  };
+} | {
+  grant_delegate: {
+    delegate: Addr;
+    expires_at: Expiration;
+    game_id?: number | null;
+    max_actions: number;
+  };
+} | {
+  revoke_delegate: {
+    delegate: Addr;
+  };
+} | {
+  relay_signed: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  };
+} | {
+  advance_round: {
+    game_id: number;
+  };
 } | {
   end_game: {
     game_id: number;
   };
 };
+export type AbsencePolicy = "forfeit" | "void" | {
+  default_move: {
+    value: string;
+  };
+};
 export type Uint128 = string;
-export interface GameConfig {
+export type Duration = {
+  height: number;
+} | {
+  time: number;
+};
+export type BargainingMode = "demand" | {
+  alternating_offers: {
+    discount: Decimal;
+  };
+};
+export type Decimal = string;
+export type OverflowRule = "zero" | "proportional" | "first_revealer";
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
+};
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type RelayAction = {
+  commit: {
+    amount?: Uint128 | null;
+    value: string;
+  };
+} | {
+  reveal: {
+    nonce: number;
+    value: string;
+  };
+};
+export interface GameConfigForTradeConfig {
+  absence_policy: AbsencePolicy;
+  bond_treasury?: Addr | null;
+  commit_bond?: Uint128 | null;
+  commit_duration?: Duration | null;
+  extension: TradeConfig;
   game_joining_fee?: Uint128 | null;
   has_turns: boolean;
   max_players?: number | null;
   max_rounds: number;
   min_deposit: Uint128;
   min_players: number;
-  round_expiry_duration?: number | null;
+  reveal_duration?: Duration | null;
   round_reward_multiplier?: number | null;
   skip_reveal: boolean;
 }
+export interface TradeConfig {
+  mode: BargainingMode;
+  multiplier: Uint128;
+  overflow: OverflowRule;
+  pie: number;
+}
+export interface TelegramAttestation {
+  expires_at: number;
+  signature: Binary;
+}
+export interface RelayPayload {
+  action: RelayAction;
+  contract: Addr;
+  game_id: number;
+  nonce: number;
+  round: number;
+}
 export type QueryMsg = {
+  get_pie: {
+    game_id: number;
+  };
+} | {
   get_game: {
     game_id: number;
   };
@@ -78,18 +185,51 @@ export type QueryMsg = {
   };
 } | {
   get_games_count: {};
+} | {
+  get_player_stats: {
+    player: Addr;
+  };
+} | {
+  get_player_stats_by_telegram_id: {
+    telegram_id: string;
+  };
+} | {
+  get_delegation: {
+    delegate: Addr;
+    player: Addr;
+  };
+} | {
+  get_relay_nonce: {
+    player: Addr;
+  };
+} | {
+  get_rating: {
+    player: Addr;
+  };
+} | {
+  get_top_ratings: {
+    limit?: number | null;
+    start_after?: Addr | null;
+  };
 };
-export type GameRoundStatus = "pending" | "committed" | "revealed" | "ended";
-export interface GameRound {
-  commits: [Addr, string, Uint128 | null][];
-  expires_at?: number | null;
-  id: number;
-  reveals: [Addr, string][];
-  status: GameRoundStatus;
+export type GameRoundStatus = "pending" | "committed" | "revealed" | "ended" | "voided";
+export interface CurrentRoundResponse {
+  expires_at?: Expiration | null;
+  expires_at_time?: Timestamp | null;
+  now: Timestamp;
+  round: number;
+  status?: GameRoundStatus | null;
+}
+export type NullableDelegation = Delegation | null;
+export interface Delegation {
+  actions_used: number;
+  expires_at: Expiration;
+  game_id?: number | null;
+  max_actions: number;
 }
 export type GameStatus = "created" | "ready" | "in_progress" | "rounds_finished" | "ended";
-export interface Game {
-  config: GameConfig;
+export interface GameForTradeConfig {
+  config: GameConfigForTradeConfig;
   creator: Addr;
   current_round: number;
   id: number;
@@ -97,10 +237,34 @@ export interface Game {
   players: [Addr, string][];
   rounds: GameRound[];
   scores: {};
+  slashed_bonds: [number, Addr, Uint128][];
   status: GameStatus;
   total_escrow: Uint128;
 }
-export type Uint64 = number;
-export interface Leaderboard {
-  leaderboard: [Addr, string, Uint128][];
-}
\ No newline at end of file
+export interface GameRound {
+  commit_expires_at?: Expiration | null;
+  commits: [Addr, string, Uint128 | null][];
+  forfeited_by: Addr[];
+  id: number;
+  reveal_expires_at?: Expiration | null;
+  reveals: [Addr, string][];
+  status: GameRoundStatus;
+}
+export type ArrayOfTupleOfAddrAndStringAndUint128 = [Addr, string, Uint128][];
+export interface PlayerStats {
+  best_win_streak: number;
+  current_win_streak: number;
+  draws: number;
+  games_played: number;
+  last_played_block: number;
+  losses: number;
+  total_rewards: Uint128;
+  wins: number;
+}
+export interface RatingResponse {
+  games_rated: number;
+  player: Addr;
+  provisional: boolean;
+  rating: Decimal;
+}
+export type ArrayOfRatingResponse = RatingResponse[];
\ No newline at end of file
@@ -0,0 +1,219 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
+import { Coin, StdFee } from "@cosmjs/amino";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForUltimatumConfig, AbsencePolicy, Uint128, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForUltimatumConfig, UltimatumConfig, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForUltimatumConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwUltimatum.types";
+export interface CwUltimatumReadOnlyInterface {
+  contractAddress: string;
+  getGame: ({
+    gameId
+  }: {
+    gameId: number;
+  }) => Promise<GameForUltimatumConfig>;
+  getLeaderboard: () => Promise<ArrayOfTupleOfAddrAndStringAndUint128>;
+  getCurrentRound: ({
+    gameId
+  }: {
+    gameId: number;
+  }) => Promise<CurrentRoundResponse>;
+  getGameStatus: ({
+    gameId
+  }: {
+    gameId: number;
+  }) => Promise<GameStatus>;
+  getGamesCount: () => Promise<Uint64>;
+  getPlayerStats: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<PlayerStats>;
+  getPlayerStatsByTelegramId: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }) => Promise<PlayerStats>;
+  getDelegation: ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }) => Promise<NullableDelegation>;
+  getRelayNonce: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<Uint64>;
+  getRating: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<RatingResponse>;
+  getTopRatings: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }) => Promise<ArrayOfRatingResponse>;
+}
+export class CwUltimatumQueryClient implements CwUltimatumReadOnlyInterface {
+  client: CosmWasmClient;
+  contractAddress: string;
+  constructor(client: CosmWasmClient, contractAddress: string) {
+    this.client = client;
+    this.contractAddress = contractAddress;
+    this.getGame = this.getGame.bind(this);
+    this.getLeaderboard = this.getLeaderboard.bind(this);
+    this.getCurrentRound = this.getCurrentRound.bind(this);
+    this.getGameStatus = this.getGameStatus.bind(this);
+    this.getGamesCount = this.getGamesCount.bind(this);
+    this.getPlayerStats = this.getPlayerStats.bind(this);
+    this.getPlayerStatsByTelegramId = this.getPlayerStatsByTelegramId.bind(this);
+    this.getDelegation = this.getDelegation.bind(this);
+    this.getRelayNonce = this.getRelayNonce.bind(this);
+    this.getRating = this.getRating.bind(this);
+    this.getTopRatings = this.getTopRatings.bind(this);
+  }
+  getGame = async ({
+    gameId
+  }: {
+    gameId: number;
+  }): Promise<GameForUltimatumConfig> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_game: {
+        game_id: gameId
+      }
+    });
+  };
+  getLeaderboard = async (): Promise<ArrayOfTupleOfAddrAndStringAndUint128> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_leaderboard: {}
+    });
+  };
+  getCurrentRound = async ({
+    gameId
+  }: {
+    gameId: number;
+  }): Promise<CurrentRoundResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_current_round: {
+        game_id: gameId
+      }
+    });
+  };
+  getGameStatus = async ({
+    gameId
+  }: {
+    gameId: number;
+  }): Promise<GameStatus> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_game_status: {
+        game_id: gameId
+      }
+    });
+  };
+  getGamesCount = async (): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_games_count: {}
+    });
+  };
+  getPlayerStats = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats: {
+        player
+      }
+    });
+  };
+  getPlayerStatsByTelegramId = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats_by_telegram_id: {
+        telegram_id: telegramId
+      }
+    });
+  };
+  getDelegation = async ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }): Promise<NullableDelegation> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegation: {
+        delegate,
+        player
+      }
+    });
+  };
+  getRelayNonce = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_relay_nonce: {
+        player
+      }
+    });
+  };
+  getRating = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<RatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_rating: {
+        player
+      }
+    });
+  };
+  getTopRatings = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }): Promise<ArrayOfRatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_top_ratings: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+}
+export interface CwUltimatumInterface extends CwUltimatumReadOnlyInterface {
+  contractAddress: string;
+  sender: string;
+  lifecycle: (executeMsgForUltimatumConfig: ExecuteMsgForUltimatumConfig, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+}
+export class CwUltimatumClient extends CwUltimatumQueryClient implements CwUltimatumInterface {
+  client: SigningCosmWasmClient;
+  sender: string;
+  contractAddress: string;
+  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
+    super(client, contractAddress);
+    this.client = client;
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lifecycle = this.lifecycle.bind(this);
+  }
+  lifecycle = async (executeMsgForUltimatumConfig: ExecuteMsgForUltimatumConfig, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      lifecycle: executeMsgForUltimatumConfig
+    }, fee, memo, _funds);
+  };
+}
\ No newline at end of file
@@ -0,0 +1,257 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+export type Binary = string;
+export type Addr = string;
+export interface InstantiateMsg {
+  attestation_pubkey?: Binary | null;
+  base_url: string;
+  identity_contract?: Addr | null;
+  image_url: string;
+  rating_config?: RatingConfig | null;
+  token_contract: Addr;
+}
+export interface RatingConfig {
+  initial_rating: number;
+  k_factor: number;
+  provisional_games: number;
+  provisional_k_factor: number;
+}
+export type ExecuteMsg = {
+  lifecycle: ExecuteMsgForUltimatumConfig;
+};
+export type ExecuteMsgForUltimatumConfig = {
+  create_game: {
+    config: GameConfigForUltimatumConfig;
+  };
+} | {
+  start_game: {
+    game_id: number;
+  };
+} | {
+  join_game: {
+    attestation?: TelegramAttestation | null;
+    game_id: number;
+    telegram_id: string;
+  };
+} | {
+  commit_round: {
+    amount?: Uint128 | null;
+    game_id: number;
+    value: string;
+  };
+} | {
+  commit_round_as_delegate: {
+    amount?: Uint128 | null;
+    game_id: number;
+    player: Addr;
+    value: string;
+  };
+} | {
+  reveal_round: {
+    game_id: number;
+    nonce: number;
+    value: string;
+  };
+} | {
+  reveal_round_as_delegate: {
+    game_id: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  };
+} | {
+  play_turn: {
+    game_id: number;
+    value: string;
+  };
+} | {
+  grant_delegate: {
+    delegate: Addr;
+    expires_at: Expiration;
+    game_id?: number | null;
+    max_actions: number;
+  };
+} | {
+  revoke_delegate: {
+    delegate: Addr;
+  };
+} | {
+  relay_signed: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  };
+} | {
+  advance_round: {
+    game_id: number;
+  };
+} | {
+  end_game: {
+    game_id: number;
+  };
+};
+export type AbsencePolicy = "forfeit" | "void" | {
+  default_move: {
+    value: string;
+  };
+};
+export type Uint128 = string;
+export type Duration = {
+  height: number;
+} | {
+  time: number;
+};
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
+};
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type RelayAction = {
+  commit: {
+    amount?: Uint128 | null;
+    value: string;
+  };
+} | {
+  reveal: {
+    nonce: number;
+    value: string;
+  };
+};
+export interface GameConfigForUltimatumConfig {
+  absence_policy: AbsencePolicy;
+  bond_treasury?: Addr | null;
+  commit_bond?: Uint128 | null;
+  commit_duration?: Duration | null;
+  extension: UltimatumConfig;
+  game_joining_fee?: Uint128 | null;
+  has_turns: boolean;
+  max_players?: number | null;
+  max_rounds: number;
+  min_deposit: Uint128;
+  min_players: number;
+  reveal_duration?: Duration | null;
+  round_reward_multiplier?: number | null;
+  skip_reveal: boolean;
+}
+export interface UltimatumConfig {
+  pot: Uint128;
+}
+export interface TelegramAttestation {
+  expires_at: number;
+  signature: Binary;
+}
+export interface RelayPayload {
+  action: RelayAction;
+  contract: Addr;
+  game_id: number;
+  nonce: number;
+  round: number;
+}
+export type QueryMsg = {
+  get_game: {
+    game_id: number;
+  };
+} | {
+  get_leaderboard: {};
+} | {
+  get_current_round: {
+    game_id: number;
+  };
+} | {
+  get_game_status: {
+    game_id: number;
+  };
+} | {
+  get_games_count: {};
+} | {
+  get_player_stats: {
+    player: Addr;
+  };
+} | {
+  get_player_stats_by_telegram_id: {
+    telegram_id: string;
+  };
+} | {
+  get_delegation: {
+    delegate: Addr;
+    player: Addr;
+  };
+} | {
+  get_relay_nonce: {
+    player: Addr;
+  };
+} | {
+  get_rating: {
+    player: Addr;
+  };
+} | {
+  get_top_ratings: {
+    limit?: number | null;
+    start_after?: Addr | null;
+  };
+};
+export type GameRoundStatus = "pending" | "committed" | "revealed" | "ended" | "voided";
+export interface CurrentRoundResponse {
+  expires_at?: Expiration | null;
+  expires_at_time?: Timestamp | null;
+  now: Timestamp;
+  round: number;
+  status?: GameRoundStatus | null;
+}
+export type NullableDelegation = Delegation | null;
+export interface Delegation {
+  actions_used: number;
+  expires_at: Expiration;
+  game_id?: number | null;
+  max_actions: number;
+}
+export type GameStatus = "created" | "ready" | "in_progress" | "rounds_finished" | "ended";
+export interface GameForUltimatumConfig {
+  config: GameConfigForUltimatumConfig;
+  creator: Addr;
+  current_round: number;
+  id: number;
+  player_escrow: [Addr, Uint128][];
+  players: [Addr, string][];
+  rounds: GameRound[];
+  scores: {};
+  slashed_bonds: [number, Addr, Uint128][];
+  status: GameStatus;
+  total_escrow: Uint128;
+}
+export interface GameRound {
+  commit_expires_at?: Expiration | null;
+  commits: [Addr, string, Uint128 | null][];
+  forfeited_by: Addr[];
+  id: number;
+  reveal_expires_at?: Expiration | null;
+  reveals: [Addr, string][];
+  status: GameRoundStatus;
+}
+export type ArrayOfTupleOfAddrAndStringAndUint128 = [Addr, string, Uint128][];
+export interface PlayerStats {
+  best_win_streak: number;
+  current_win_streak: number;
+  draws: number;
+  games_played: number;
+  last_played_block: number;
+  losses: number;
+  total_rewards: Uint128;
+  wins: number;
+}
+export type Decimal = string;
+export interface RatingResponse {
+  games_rated: number;
+  player: Addr;
+  provisional: boolean;
+  rating: Decimal;
+}
+export type ArrayOfRatingResponse = RatingResponse[];
\ No newline at end of file
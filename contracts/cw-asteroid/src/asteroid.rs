@@ -1,78 +1,196 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_game_lifecycle::{lifecycle::GameLifecycle, state::Game, ContractError as LifecycleError};
-use std::cmp::Ordering;
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Event, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{Game, GameConfig},
+    ContractError as LifecycleError,
+};
 use std::collections::HashMap;
-use std::str::FromStr;
+
+use crate::replay::{is_valid_input_log, replay_score, MAX_SCORE};
+use crate::state::{
+    Config, HighScore, CONFIG, HIGH_SCORES, HIGH_SCORE_TABLE_SIZE, PERSONAL_BESTS,
+};
+
 #[cw_serde]
-pub struct Asteroid {
-    pub accepted_value: Uint128,
+pub struct Asteroid;
+
+/// Asteroid specific parameters of a game
+#[cw_serde]
+#[derive(Default)]
+pub struct AsteroidConfig {
+    pub handicaps: Vec<Handicap>, // players without a handicap start from scratch every round
+}
+
+/// Points added to a player's score in every round their run counts, to level the field in cups
+#[cw_serde]
+pub struct Handicap {
+    pub player: Addr,
+    pub points: u64,
+}
+
+impl Asteroid {
+    /// Replayed score of every run revealed in the game, skipping rounds that were forfeited,
+    /// voided or never finished
+    fn run_scores(game: &Game<AsteroidConfig>) -> Vec<(u8, Addr, u64)> {
+        game.scored_rounds()
+            .flat_map(|round| {
+                round.reveals.iter().map(|(player, log)| {
+                    (round.id, player.clone(), replay_score(game.id, round.id, log))
+                })
+            })
+            .collect()
+    }
+
+    /// Reward of a solo run that beats the player's personal best: the highest score tier it
+    /// reaches that the previous best had not, plus a bonus. Since the asteroid field of a round
+    /// is known before the game is played, each tier is only paid once per player.
+    fn solo_reward(config: &Config, score: u64, personal_best: Option<u64>) -> Uint128 {
+        if personal_best.is_some_and(|best| score <= best) {
+            return Uint128::zero();
+        }
+
+        let tier_reward = config
+            .score_tiers
+            .iter()
+            .filter(|tier| score >= tier.score)
+            .filter(|tier| personal_best.map_or(true, |best| best < tier.score))
+            .map(|tier| tier.reward)
+            .max()
+            .unwrap_or_default();
+        tier_reward + config.personal_best_reward
+    }
 }
 
 impl GameLifecycle for Asteroid {
-    fn calculate_rewards_and_winners(game: &mut Game) -> Result<bool, LifecycleError> {
-        let mut winnings: HashMap<Addr, Uint128> = HashMap::from([
-            (game.players[0].0.clone(), Uint128::zero()),
-            (game.players[1].0.clone(), Uint128::zero()),
-        ]);
-
-        // Since the game is only 1 round, we directly access the first round
-        let round = game
-            .rounds
-            .get(0)
-            .expect("Game must have at least one round");
-        let reveals = round.reveals.as_slice();
-
-        match (reveals.get(0), reveals.get(1)) {
-            (Some(p1_reveal), Some(p2_reveal)) => {
-                // Directly compare the scores
-                let p1_score = u64::from_str(&p1_reveal.1).unwrap();
-                let p2_score = u64::from_str(&p2_reveal.1).unwrap();
-
-                match p1_score.cmp(&p2_score) {
-                    Ordering::Greater => {
-                        winnings.insert(
-                            reveals[0].0.clone(),
-                            winnings.get(&reveals[0].0).unwrap() + Uint128::from(100u128),
-                        );
-                    }
-                    Ordering::Less => {
-                        winnings.insert(
-                            reveals[1].0.clone(),
-                            winnings.get(&reveals[1].0).unwrap() + Uint128::from(100u128),
-                        );
-                    }
-                    Ordering::Equal => {
-                        // In case of a tie, both players get their score
-                        winnings.insert(
-                            reveals[0].0.clone(),
-                            winnings.get(&reveals[0].0).unwrap() + Uint128::from(50u128),
-                        );
-                        winnings.insert(
-                            reveals[1].0.clone(),
-                            winnings.get(&reveals[1].0).unwrap() + Uint128::from(50u128),
-                        );
-                    }
+    type Extension = AsteroidConfig;
+
+    fn is_valid_reveal_choice(value: &String) -> bool {
+        // players reveal their input log rather than a score, see `replay_score`
+        is_valid_input_log(value)
+    }
+
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<AsteroidConfig>,
+    ) -> Result<(), LifecycleError> {
+        if config.extension.handicaps.iter().any(|h| h.points > MAX_SCORE) {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: format!("handicaps cannot be above the maximum score of {}", MAX_SCORE),
+            });
+        }
+        Ok(())
+    }
+
+    fn calculate_rewards_and_winners(
+        deps: Deps,
+        game: &mut Game<AsteroidConfig>,
+    ) -> Result<bool, LifecycleError> {
+        let config = CONFIG.load(deps.storage)?;
+        let mut winnings: HashMap<Addr, Uint128> = game
+            .players
+            .iter()
+            .map(|(player, _)| (player.clone(), Uint128::zero()))
+            .collect();
+
+        // the score of each run is computed by replaying its input log, runs below the accepted
+        // value do not count
+        let runs = Self::run_scores(game);
+        let counts = |score: u64| Uint128::from(score) >= config.accepted_value;
+
+        if game.players.len() == 1 {
+            // every solo run is rewarded against the score tiers and the personal best so far
+            let player = &game.players[0].0;
+            let mut personal_best = PERSONAL_BESTS
+                .may_load(deps.storage, player)?
+                .map(|best| best.score);
+            let mut reward = Uint128::zero();
+            for (_, _, score) in runs {
+                if counts(score) {
+                    reward += Self::solo_reward(&config, score, personal_best);
                 }
+                personal_best = personal_best.max(Some(score));
             }
-            // p1 revealed, p2 did not reveal, p1 gets their score
-            (Some(p1_reveal), None) => {
-                winnings.insert(
-                    p1_reveal.0.clone(),
-                    winnings.get(&p1_reveal.0).unwrap() + Uint128::from(100u128),
-                );
+            winnings.insert(player.clone(), reward);
+        } else {
+            // scores accumulate over the rounds, with the player's handicap added to each run
+            let handicap = |player: &Addr| {
+                game.config
+                    .extension
+                    .handicaps
+                    .iter()
+                    .find(|h| h.player == *player)
+                    .map_or(0, |h| h.points)
+            };
+            let mut totals: HashMap<Addr, u64> = HashMap::new();
+            for (_, player, score) in runs.into_iter().filter(|run| counts(run.2)) {
+                *totals.entry(player.clone()).or_default() += score + handicap(&player);
             }
-            // p2 revealed, p1 did not reveal, p2 gets their score
-            (None, Some(p2_reveal)) => {
-                winnings.insert(
-                    p2_reveal.0.clone(),
-                    winnings.get(&p2_reveal.0).unwrap() + Uint128::from(100u128),
-                );
+
+            // the best total wins, in case of a tie the winners share the reward
+            let best = totals.values().max().copied();
+            let winners: Vec<&Addr> = totals
+                .iter()
+                .filter(|(_, total)| Some(**total) == best)
+                .map(|(player, _)| player)
+                .collect();
+            let reward = match winners.len() {
+                1 => Uint128::from(100u128),
+                _ => Uint128::from(50u128),
+            };
+            for winner in winners {
+                winnings.insert(winner.clone(), reward);
             }
-            _ => {}
         }
 
         game.scores = winnings;
         Ok(true)
     }
+
+    fn on_game_ended(
+        deps: DepsMut,
+        _env: &Env,
+        game: &Game<AsteroidConfig>,
+    ) -> Result<Vec<Event>, LifecycleError> {
+        let mut high_scores = HIGH_SCORES.may_load(deps.storage)?.unwrap_or_default();
+        let mut events = vec![];
+
+        for (round, player, score) in Self::run_scores(game) {
+            let telegram_id = game
+                .players
+                .iter()
+                .find(|p| p.0 == player)
+                .map(|p| p.1.clone())
+                .unwrap_or_default();
+            let run = HighScore {
+                player: player.clone(),
+                telegram_id,
+                score,
+                game_id: game.id,
+                round,
+            };
+
+            let personal_best = PERSONAL_BESTS.may_load(deps.storage, &player)?;
+            if personal_best.map_or(true, |best| score > best.score) {
+                PERSONAL_BESTS.save(deps.storage, &player, &run)?;
+                events.push(
+                    Event::new("personal_best")
+                        .add_attribute("game_id", game.id.to_string())
+                        .add_attribute("player", player.to_string())
+                        .add_attribute("score", score.to_string()),
+                );
+            }
+
+            // earlier runs keep their place on equal scores
+            let position = high_scores.iter().position(|h| score > h.score);
+            match position {
+                Some(position) => high_scores.insert(position, run),
+                None => high_scores.push(run),
+            }
+            high_scores.truncate(HIGH_SCORE_TABLE_SIZE);
+        }
+
+        HIGH_SCORES.save(deps.storage, &high_scores)?;
+        Ok(events)
+    }
 }
@@ -1,11 +1,15 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
 use cw2::set_contract_version;
 
 use crate::asteroid::Asteroid;
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::replay::MAX_SCORE;
+use crate::state::{Config, CONFIG, HIGH_SCORES, HIGH_SCORE_TABLE_SIZE, PERSONAL_BESTS};
 use cw_game_lifecycle::lifecycle::GameLifecycle;
 
 const CONTRACT_NAME: &str = "crates.io:cw-asteroid";
@@ -19,6 +23,20 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // a threshold no run can reach would withhold every reward
+    if msg.accepted_value > Uint128::from(MAX_SCORE) {
+        return Err(ContractError::InvalidAcceptedValue {
+            max_score: MAX_SCORE,
+        });
+    }
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            accepted_value: msg.accepted_value,
+            score_tiers: msg.score_tiers.clone(),
+            personal_best_reward: msg.personal_best_reward,
+        },
+    )?;
     Asteroid::instantiate(deps, env, info, msg.into()).map_err(ContractError::GameLifecycle)?;
 
     Ok(Response::new().add_attribute("action", "instantiate"))
@@ -41,8 +59,16 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        // ... custom queries specific to this contract
-        _ => Asteroid::query(deps, env, msg.into()),
+        QueryMsg::GetConfig {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::GetHighScores { limit } => {
+            let limit = limit.map_or(HIGH_SCORE_TABLE_SIZE, |l| l as usize);
+            let high_scores = HIGH_SCORES.may_load(deps.storage)?.unwrap_or_default();
+            to_json_binary(&high_scores.into_iter().take(limit).collect::<Vec<_>>())
+        }
+        QueryMsg::GetPersonalBest { player } => {
+            to_json_binary(&PERSONAL_BESTS.may_load(deps.storage, &player)?)
+        }
+        _ => Asteroid::query(deps, env, msg.try_into()?),
     }
 }
 
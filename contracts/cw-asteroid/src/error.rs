@@ -14,6 +14,9 @@ pub enum ContractError {
 
     #[error("Unauthorized")]
     Unauthorized {},
+
+    #[error("Accepted value cannot be above the maximum score of {max_score}")]
+    InvalidAcceptedValue { max_score: u64 },
     // Add any other custom errors you like here.
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
@@ -3,6 +3,8 @@ pub mod contract;
 mod error;
 pub mod helpers;
 pub mod msg;
+pub mod replay;
 pub mod state;
+mod test;
 
 pub use crate::error::ContractError;
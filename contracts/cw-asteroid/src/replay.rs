@@ -0,0 +1,148 @@
+use sha2::{Digest, Sha256};
+
+/// Longest input log accepted, one action per tick. This bounds both the size of a reveal and
+/// the gas spent replaying it.
+pub const MAX_INPUT_TICKS: usize = 3_600;
+
+/// Highest score a valid log can earn: surviving every tick and firing every time the cannon
+/// is ready
+pub const MAX_SCORE: u64 = MAX_INPUT_TICKS as u64 * SURVIVAL_POINTS
+    + (MAX_INPUT_TICKS as u64).div_ceil(FIRE_COOLDOWN) * ASTEROID_POINTS;
+
+/// Number of lanes the ship can move between
+const LANES: u8 = 7;
+/// Row asteroids spawn at, they fall one row per tick until they reach the ship at row 0
+const FIELD_HEIGHT: u8 = 12;
+/// Ticks the ship has to wait between two shots
+const FIRE_COOLDOWN: u64 = 3;
+/// Points for every tick the ship survives
+const SURVIVAL_POINTS: u64 = 1;
+/// Points for every asteroid shot down
+const ASTEROID_POINTS: u64 = 10;
+
+/// A single tick of ship input
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShipAction {
+    Idle,  // '.'
+    Left,  // 'L'
+    Right, // 'R'
+    Fire,  // 'F'
+}
+
+impl TryFrom<char> for ShipAction {
+    type Error = ();
+
+    fn try_from(action: char) -> Result<Self, Self::Error> {
+        match action {
+            '.' => Ok(ShipAction::Idle),
+            'L' => Ok(ShipAction::Left),
+            'R' => Ok(ShipAction::Right),
+            'F' => Ok(ShipAction::Fire),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Checks the input log is within bounds and only contains known actions
+pub fn is_valid_input_log(log: &str) -> bool {
+    log.len() <= MAX_INPUT_TICKS && log.chars().all(|c| ShipAction::try_from(c).is_ok())
+}
+
+/// xorshift64 generator driving the asteroid field, seeded from the game and round so every
+/// player in a round faces the same field
+struct FieldRng(u64);
+
+impl FieldRng {
+    fn new(game_id: u64, round: u8) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(game_id.to_be_bytes());
+        hasher.update([round]);
+        let seed = u64::from_be_bytes(hasher.finalize()[..8].try_into().unwrap());
+        // xorshift never leaves the zero state
+        FieldRng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Replays an input log against the asteroid field of the given game round and returns the
+/// score it earns. Replay stops when the ship is hit or the log runs out. Logs must be checked
+/// with `is_valid_input_log` first, unknown actions are treated as idle.
+pub fn replay_score(game_id: u64, round: u8, log: &str) -> u64 {
+    let mut rng = FieldRng::new(game_id, round);
+    let mut ship_lane = LANES / 2;
+    let mut asteroids: Vec<(u8, u8)> = vec![]; // (lane, row)
+    let mut next_shot = 0u64;
+    let mut score = 0u64;
+
+    for (tick, action) in log.chars().take(MAX_INPUT_TICKS).enumerate() {
+        let tick = tick as u64;
+        match ShipAction::try_from(action).unwrap_or(ShipAction::Idle) {
+            ShipAction::Idle => {}
+            ShipAction::Left => ship_lane = ship_lane.saturating_sub(1),
+            ShipAction::Right => ship_lane = (ship_lane + 1).min(LANES - 1),
+            ShipAction::Fire if tick >= next_shot => {
+                next_shot = tick + FIRE_COOLDOWN;
+                // the shot hits the closest asteroid in the ship's lane
+                let target = asteroids
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (lane, _))| *lane == ship_lane)
+                    .min_by_key(|(_, (_, row))| *row)
+                    .map(|(i, _)| i);
+                if let Some(i) = target {
+                    asteroids.remove(i);
+                    score += ASTEROID_POINTS;
+                }
+            }
+            ShipAction::Fire => {}
+        }
+
+        for asteroid in asteroids.iter_mut() {
+            asteroid.1 -= 1;
+        }
+        if asteroids.iter().any(|(lane, row)| *row == 0 && *lane == ship_lane) {
+            break;
+        }
+        asteroids.retain(|(_, row)| *row > 0);
+
+        // asteroids spawn more often the longer the ship survives
+        let spawn_chance = (20 + tick / 60).min(60);
+        let roll = rng.next();
+        if roll % 100 < spawn_chance {
+            asteroids.push((((roll >> 8) % LANES as u64) as u8, FIELD_HEIGHT));
+        }
+
+        score += SURVIVAL_POINTS;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_is_deterministic_per_round() {
+        let log = "F.L.F.R.F.".repeat(50);
+
+        assert_eq!(replay_score(1, 1, &log), replay_score(1, 1, &log));
+        assert!(replay_score(1, 1, &log) <= MAX_SCORE);
+        assert_ne!(FieldRng::new(1, 1).next(), FieldRng::new(1, 2).next());
+        assert_ne!(FieldRng::new(1, 1).next(), FieldRng::new(2, 1).next());
+    }
+
+    #[test]
+    fn input_logs_are_bounded() {
+        assert!(is_valid_input_log(""));
+        assert!(is_valid_input_log(&"F".repeat(MAX_INPUT_TICKS)));
+        assert!(!is_valid_input_log(&"F".repeat(MAX_INPUT_TICKS + 1)));
+        assert!(!is_valid_input_log("18446744073709551615"));
+    }
+}
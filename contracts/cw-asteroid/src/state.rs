@@ -1 +1,33 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
 
+/// Number of runs kept in the high score table
+pub const HIGH_SCORE_TABLE_SIZE: usize = 10;
+
+pub const CONFIG: Item<Config> = Item::new("asteroid_config");
+pub const HIGH_SCORES: Item<Vec<HighScore>> = Item::new("high_scores"); // best runs, highest score first
+pub const PERSONAL_BESTS: Map<&Addr, HighScore> = Map::new("personal_bests"); // (player, best run)
+
+/// Contract wide settings, fixed at instantiate
+#[cw_serde]
+pub struct Config {
+    pub accepted_value: Uint128, // minimum score a player must reach to earn any reward
+    pub score_tiers: Vec<ScoreTier>, // solo rewards, the highest tier reached is paid
+    pub personal_best_reward: Uint128, // paid on top of the tier for beating a personal best solo
+}
+
+#[cw_serde]
+pub struct ScoreTier {
+    pub score: u64,
+    pub reward: Uint128,
+}
+
+#[cw_serde]
+pub struct HighScore {
+    pub player: Addr,
+    pub telegram_id: String,
+    pub score: u64,
+    pub game_id: u64,
+    pub round: u8,
+}
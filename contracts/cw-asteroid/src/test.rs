@@ -0,0 +1,536 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+    use sha2::{Digest, Sha256};
+
+    use crate::asteroid::{AsteroidConfig, Handicap};
+    use crate::replay::{replay_score, MAX_INPUT_TICKS, MAX_SCORE};
+    use crate::state::{Config, HighScore, ScoreTier};
+
+    fn setup_contracts(app: &mut App, config: Config) -> (Addr, Addr) {
+        let owner = app.api().addr_make("owner");
+        let p2e_code_id = app.store_code(Box::new(ContractWrapper::new(
+            cw_p2e::contract::execute,
+            cw_p2e::contract::instantiate,
+            cw_p2e::contract::query,
+        )));
+        let init_msg = cw_p2e::msg::InstantiateMsg {
+            name: "glitch".to_string(),
+            symbol: "glitch".to_string(),
+            decimals: 18,
+            initial_supply: Uint128::new(1_000_000),
+            initial_balances: vec![],
+            reward_per_block: Uint128::new(1000),
+            mint: None,
+            marketing: None,
+        };
+        let p2e_contract = app
+            .instantiate_contract(p2e_code_id, owner.clone(), &init_msg, &[], "p2e", None)
+            .unwrap();
+
+        let asteroid_code_id = app.store_code(Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )));
+        let init_msg = crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract: p2e_contract.clone(),
+            rating_config: None,
+            identity_contract: None,
+            attestation_pubkey: None,
+            accepted_value: config.accepted_value,
+            score_tiers: config.score_tiers,
+            personal_best_reward: config.personal_best_reward,
+        };
+        let asteroid_contract = app
+            .instantiate_contract(asteroid_code_id, owner.clone(), &init_msg, &[], "asteroid", None)
+            .unwrap();
+
+        // allow the game contract to mint rewards
+        let msg = cw_p2e::msg::ExecuteMsg::AuthorizeRewardsIssuer { address: asteroid_contract.to_string() };
+        app.execute_contract(owner, p2e_contract.clone(), &msg, &[]).unwrap();
+
+        (p2e_contract, asteroid_contract)
+    }
+
+    fn config(accepted_value: u128) -> Config {
+        Config {
+            accepted_value: Uint128::new(accepted_value),
+            score_tiers: vec![],
+            personal_best_reward: Uint128::zero(),
+        }
+    }
+
+    fn hash_score(score: &str, nonce: u64) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(score.as_bytes());
+        hasher.update(nonce.to_be_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    fn lifecycle_msg(msg: cw_game_lifecycle::msg::ExecuteMsg<AsteroidConfig>) -> crate::msg::ExecuteMsg {
+        crate::msg::ExecuteMsg::Lifecycle(msg)
+    }
+
+    #[test]
+    fn asteroid_config_is_validated() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let (_, asteroid_contract) = setup_contracts(&mut app, config(0));
+
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+            config: cw_game_lifecycle::state::GameConfig {
+                max_rounds: 3,
+                extension: AsteroidConfig {
+                    handicaps: vec![Handicap { player: p1.clone(), points: MAX_SCORE + 1 }],
+                },
+                ..Default::default()
+            },
+        });
+        let err = app.execute_contract(p1, asteroid_contract, &msg, &[]).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: format!("handicaps cannot be above the maximum score of {}", MAX_SCORE),
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn asteroid_accepted_value_cannot_exceed_max_score() {
+        let mut app = App::default();
+        let owner = app.api().addr_make("owner");
+        let asteroid_code_id = app.store_code(Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )));
+        let init_msg = crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract: app.api().addr_make("p2e"),
+            rating_config: None,
+            identity_contract: None,
+            attestation_pubkey: None,
+            accepted_value: Uint128::from(MAX_SCORE + 1),
+            score_tiers: vec![],
+            personal_best_reward: Uint128::zero(),
+        };
+        let err = app
+            .instantiate_contract(asteroid_code_id, owner, &init_msg, &[], "asteroid", None)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::InvalidAcceptedValue { max_score: MAX_SCORE }.to_string()
+        );
+    }
+
+    #[test]
+    fn asteroid_scores_below_accepted_value_do_not_count() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (_, asteroid_contract) = setup_contracts(&mut app, config(500));
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CreateGame { config });
+        app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+
+        // the accepted value is set for the whole contract at instantiate
+        let config: crate::state::Config = app
+            .wrap()
+            .query_wasm_smart(asteroid_contract.clone(), &crate::msg::QueryMsg::GetConfig {})
+            .unwrap();
+        assert_eq!(config.accepted_value, Uint128::new(500));
+
+        for player in [&p1, &p2] {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id: 0,
+                telegram_id: player.to_string(),
+                attestation: None,
+            });
+            app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        }
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id: 0 });
+        app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+
+        // player 1 survives longer, but neither log is long enough to reach the accepted value
+        let scores = [(&p1, "F.L.F.R.F.F.L.F.R.F."), (&p2, "F.L.F.R.F.")];
+        for (player, score) in scores {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_score(score, 123),
+                amount: None,
+            });
+            app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        }
+        for (player, score) in scores {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: score.to_string(),
+                nonce: 123,
+            });
+            app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        }
+
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 });
+        let res = app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        let winnings: Vec<&str> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .flat_map(|e| e.attributes.iter().filter(|a| a.key == "score"))
+            .map(|a| a.value.as_str())
+            .collect();
+        assert_eq!(winnings, vec!["0", "0"]);
+    }
+
+    #[test]
+    fn asteroid_scores_are_replayed_from_input_logs() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (_, asteroid_contract) = setup_contracts(&mut app, config(0));
+
+        let forged_score = u64::MAX.to_string();
+        let oversized_log = ".".repeat(MAX_INPUT_TICKS + 1);
+        let p1_log = "F.R.F.L.F.".repeat(30);
+        let p2_log = "F.F.F.".repeat(5);
+
+        // game 0 gets plain scores and game 1 gets input logs
+        for (game_id, moves) in [(0, [&forged_score, &oversized_log]), (1, [&p1_log, &p2_log])] {
+            let config = cw_game_lifecycle::state::GameConfig {
+                max_players: Some(2),
+                max_rounds: 1,
+                ..Default::default()
+            };
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CreateGame { config });
+            app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+            for player in [&p1, &p2] {
+                let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                    game_id,
+                    telegram_id: player.to_string(),
+                    attestation: None,
+                });
+                app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+            }
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id });
+            app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+
+            for (player, value) in [(&p1, moves[0]), (&p2, moves[1])] {
+                let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                    game_id,
+                    value: hash_score(value, 123),
+                    amount: None,
+                });
+                app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+            }
+        }
+
+        // a plain score and a log over the tick limit are both rejected
+        for (player, value) in [(&p1, &forged_score), (&p2, &oversized_log)] {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: value.clone(),
+                nonce: 123,
+            });
+            let err = app
+                .execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                crate::ContractError::GameLifecycle(
+                    cw_game_lifecycle::ContractError::InvalidRevealChoice { game_id: 0, round: 1 }
+                )
+                .to_string()
+            );
+        }
+
+        // input logs are replayed against the asteroid field of game 1, round 1
+        for (player, value) in [(&p1, &p1_log), (&p2, &p2_log)] {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 1,
+                value: value.clone(),
+                nonce: 123,
+            });
+            app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        }
+        assert!(replay_score(1, 1, &p1_log) > replay_score(1, 1, &p2_log));
+
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 1 });
+        let res = app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        let winnings: Vec<(&str, &str)> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .map(|e| {
+                let attr = |key: &str| {
+                    e.attributes.iter().find(|a| a.key == key).unwrap().value.as_str()
+                };
+                (attr("player"), attr("score"))
+            })
+            .collect();
+        assert!(winnings.contains(&(p1.as_str(), "100")));
+        assert!(winnings.contains(&(p2.as_str(), "0")));
+    }
+
+    fn play_solo(app: &mut App, contract: &Addr, player: &Addr, game_id: u64, log: &str) -> u128 {
+        let config = cw_game_lifecycle::state::GameConfig {
+            min_players: 1,
+            max_players: Some(1),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msgs = vec![
+            cw_game_lifecycle::msg::ExecuteMsg::CreateGame { config },
+            cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id,
+                telegram_id: player.to_string(),
+                attestation: None,
+            },
+            cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id },
+            cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id,
+                value: hash_score(log, 123),
+                amount: None,
+            },
+            cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id,
+                value: log.to_string(),
+                nonce: 123,
+            },
+        ];
+        for msg in msgs {
+            app.execute_contract(player.clone(), contract.clone(), &lifecycle_msg(msg), &[])
+                .unwrap();
+        }
+
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id });
+        let res = app.execute_contract(player.clone(), contract.clone(), &msg, &[]).unwrap();
+        res.events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .flat_map(|e| e.attributes.iter().filter(|a| a.key == "score"))
+            .map(|a| a.value.parse::<u128>().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn asteroid_solo_runs_keep_high_scores() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let (_, asteroid_contract) = setup_contracts(
+            &mut app,
+            Config {
+                accepted_value: Uint128::zero(),
+                score_tiers: vec![
+                    ScoreTier { score: 50, reward: Uint128::new(20) },
+                    ScoreTier { score: 150, reward: Uint128::new(60) },
+                ],
+                personal_best_reward: Uint128::new(10),
+            },
+        );
+        let tier_reward = |score: u64| match score {
+            150.. => 60,
+            50.. => 20,
+            _ => 0,
+        };
+
+        // the first run is a personal best
+        let best_log = "F.R.F.L.F.".repeat(30);
+        let best_score = replay_score(0, 1, &best_log);
+        let reward = play_solo(&mut app, &asteroid_contract, &p1, 0, &best_log);
+        assert_eq!(reward, tier_reward(best_score) + 10);
+
+        // a worse run earns nothing, its tier was already reached by the personal best
+        let short_log = "F.F.F.".repeat(5);
+        let short_score = replay_score(1, 1, &short_log);
+        assert!(short_score < best_score && tier_reward(short_score) > 0);
+        let reward = play_solo(&mut app, &asteroid_contract, &p1, 1, &short_log);
+        assert_eq!(reward, 0);
+
+        let personal_best: Option<HighScore> = app
+            .wrap()
+            .query_wasm_smart(
+                asteroid_contract.clone(),
+                &crate::msg::QueryMsg::GetPersonalBest { player: p1.clone() },
+            )
+            .unwrap();
+        assert_eq!(personal_best.map(|best| (best.score, best.game_id)), Some((best_score, 0)));
+
+        // both runs are in the table, best first
+        let high_scores: Vec<HighScore> = app
+            .wrap()
+            .query_wasm_smart(
+                asteroid_contract.clone(),
+                &crate::msg::QueryMsg::GetHighScores { limit: None },
+            )
+            .unwrap();
+        let table: Vec<(String, u64)> = high_scores
+            .into_iter()
+            .map(|h| (h.telegram_id, h.score))
+            .collect();
+        assert_eq!(table, vec![(p1.to_string(), best_score), (p1.to_string(), short_score)]);
+
+        let high_scores: Vec<HighScore> = app
+            .wrap()
+            .query_wasm_smart(
+                asteroid_contract.clone(),
+                &crate::msg::QueryMsg::GetHighScores { limit: Some(1) },
+            )
+            .unwrap();
+        assert_eq!(high_scores.len(), 1);
+    }
+
+    #[test]
+    fn asteroid_solo_tiers_are_paid_once() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let (_, asteroid_contract) = setup_contracts(
+            &mut app,
+            Config {
+                accepted_value: Uint128::zero(),
+                score_tiers: vec![
+                    ScoreTier { score: 50, reward: Uint128::new(20) },
+                    ScoreTier { score: 150, reward: Uint128::new(60) },
+                ],
+                personal_best_reward: Uint128::new(10),
+            },
+        );
+
+        // the first run reaches the top tier
+        let log = "F.R.F.L.F.".repeat(30);
+        assert_eq!(replay_score(0, 1, &log), 443);
+        assert_eq!(play_solo(&mut app, &asteroid_contract, &p1, 0, &log), 60 + 10);
+
+        // replaying the same log in another game reaches the same tier again without paying it
+        assert_eq!(replay_score(1, 1, &log), 336);
+        assert_eq!(play_solo(&mut app, &asteroid_contract, &p1, 1, &log), 0);
+
+        // a new personal best in the same tier only earns the personal best bonus
+        let log = "F.R.F.L.F.".repeat(40);
+        assert_eq!(replay_score(2, 1, &log), 323);
+        assert_eq!(play_solo(&mut app, &asteroid_contract, &p1, 2, &log), 0);
+        assert_eq!(replay_score(3, 1, &log), 660);
+        assert_eq!(play_solo(&mut app, &asteroid_contract, &p1, 3, &log), 10);
+    }
+
+    fn play_round(app: &mut App, contract: &Addr, game_id: u64, runs: &[(&Addr, &str)]) {
+        for (player, log) in runs {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id,
+                value: hash_score(log, 123),
+                amount: None,
+            });
+            app.execute_contract((*player).clone(), contract.clone(), &msg, &[]).unwrap();
+        }
+        for (player, log) in runs {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id,
+                value: log.to_string(),
+                nonce: 123,
+            });
+            app.execute_contract((*player).clone(), contract.clone(), &msg, &[]).unwrap();
+        }
+    }
+
+    #[test]
+    fn asteroid_cup_scores_accumulate_over_rounds() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (_, asteroid_contract) = setup_contracts(&mut app, config(0));
+
+        // player 1 flies the better runs, player 2 gets a handicap large enough to overtake
+        let p1_log = "F.R.F.L.F.".repeat(30);
+        let p2_log = "F.F.F.".repeat(5);
+        let p1_total = replay_score(0, 1, &p1_log) + replay_score(0, 3, &p1_log);
+        let p2_total = replay_score(0, 1, &p2_log) + replay_score(0, 3, &p2_log);
+        assert!(p1_total > p2_total);
+        let points = (p1_total - p2_total) / 2 + 1;
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 3,
+            absence_policy: cw_game_lifecycle::state::AbsencePolicy::Void,
+            extension: AsteroidConfig {
+                handicaps: vec![Handicap { player: p2.clone(), points }],
+            },
+            ..Default::default()
+        };
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::CreateGame { config });
+        app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        for player in [&p1, &p2] {
+            let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id: 0,
+                telegram_id: player.to_string(),
+                attestation: None,
+            });
+            app.execute_contract(player.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        }
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id: 0 });
+        app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+
+        play_round(&mut app, &asteroid_contract, 0, &[(&p1, &p1_log), (&p2, &p2_log)]);
+
+        // round 2 is abandoned by player 2 and voided once player 1 reveals after the commit window
+        let msgs = [
+            cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_score(&p1_log, 123),
+                amount: None,
+            },
+            cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound { game_id: 0 },
+            cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: p1_log.clone(),
+                nonce: 123,
+            },
+        ];
+        for msg in msgs {
+            if matches!(msg, cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound { .. }) {
+                app.update_block(|block| block.height += 101);
+            }
+            app.execute_contract(p1.clone(), asteroid_contract.clone(), &lifecycle_msg(msg), &[])
+                .unwrap();
+        }
+
+        play_round(&mut app, &asteroid_contract, 0, &[(&p1, &p1_log), (&p2, &p2_log)]);
+
+        let msg = lifecycle_msg(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 });
+        let res = app.execute_contract(p1.clone(), asteroid_contract.clone(), &msg, &[]).unwrap();
+        let winnings: Vec<(&str, &str)> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .map(|e| {
+                let attr = |key: &str| {
+                    e.attributes.iter().find(|a| a.key == key).unwrap().value.as_str()
+                };
+                (attr("player"), attr("score"))
+            })
+            .collect();
+        assert!(winnings.contains(&(p1.as_str(), "0")));
+        assert!(winnings.contains(&(p2.as_str(), "100")));
+
+        // the voided round is left out of the high score table
+        let high_scores: Vec<HighScore> = app
+            .wrap()
+            .query_wasm_smart(
+                asteroid_contract.clone(),
+                &crate::msg::QueryMsg::GetHighScores { limit: None },
+            )
+            .unwrap();
+        let mut rounds: Vec<u8> = high_scores.iter().map(|h| h.round).collect();
+        rounds.sort();
+        assert_eq!(rounds, vec![1, 1, 3, 3]);
+    }
+}
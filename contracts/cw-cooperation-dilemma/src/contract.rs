@@ -1,12 +1,14 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
 
 use cw_game_lifecycle::lifecycle::GameLifecycle;
-use crate::dilemma::CooperationDilemma;
+use cw_game_lifecycle::state::games;
+use crate::dilemma::{CooperationDilemma, DilemmaConfig};
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, MigrateMsg};
+use crate::state::PAYOFFS;
 
 const CONTRACT_NAME: &str = "crates.io:cw-cooperation-dilemma";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -19,6 +21,11 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let payoffs = msg.payoffs.clone().unwrap_or_default();
+    payoffs
+        .validate()
+        .map_err(|reason| ContractError::InvalidPayoffs { reason })?;
+    PAYOFFS.save(deps.storage, &payoffs)?;
     CooperationDilemma::instantiate(deps, env, info, msg.into())
         .map_err(ContractError::GameLifecycle)?;
 
@@ -41,8 +48,16 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        // ... custom queries specific to this contract
-        _ => CooperationDilemma::query(deps, env, msg.into()),
+        QueryMsg::GetPayoffs { game_id } => {
+            let extension = match game_id {
+                Some(game_id) => {
+                    games::<DilemmaConfig>().load(deps.storage, game_id)?.config.extension
+                }
+                None => DilemmaConfig::default(),
+            };
+            to_json_binary(&CooperationDilemma::payoffs(deps, &extension)?)
+        }
+        _ => CooperationDilemma::query(deps, env, msg.try_into()?),
     }
 }
 
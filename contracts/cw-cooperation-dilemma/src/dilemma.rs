@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_game_lifecycle::{lifecycle::GameLifecycle, state::Game, ContractError as LifecycleError};
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{Game, GameConfig},
+    ContractError as LifecycleError,
+};
 
+use crate::state::PAYOFFS;
 use crate::ContractError;
 
 #[cw_serde]
@@ -10,6 +15,77 @@ pub struct CooperationDilemma {
     pub choices: Vec<CooperationDilemmaChoices>,
 }
 
+/// Cooperation dilemma specific parameters of a game
+#[cw_serde]
+#[derive(Default)]
+pub struct DilemmaConfig {
+    pub payoffs: Option<Payoffs>, // overrides the contract's payoffs, see `QueryMsg::GetPayoffs`
+}
+
+/// Payoff matrix of a round, paid to each player depending on both choices
+#[cw_serde]
+pub struct Payoffs {
+    pub temptation: Uint128, // defecting against a cooperator
+    pub reward: Uint128, // both players cooperate
+    pub punishment: Uint128, // both players defect
+    pub sucker: Uint128, // cooperating against a defector
+}
+
+impl Default for Payoffs {
+    /// T90/R50/P10/S0. This replaces the previously hardcoded 100/50/0/0 payouts, where mutual
+    /// defection paid the same as being the sucker and defecting was never worse than cooperating
+    fn default() -> Self {
+        Self {
+            temptation: Uint128::new(90),
+            reward: Uint128::new(50),
+            punishment: Uint128::new(10),
+            sucker: Uint128::zero(),
+        }
+    }
+}
+
+impl Payoffs {
+    /// Checks the payoffs make a prisoner's dilemma, i.e. T > R > P > S and 2R > T + S so that
+    /// mutual cooperation pays more than taking turns exploiting each other
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.temptation > self.reward
+            && self.reward > self.punishment
+            && self.punishment > self.sucker)
+        {
+            return Err(String::from(
+                "payoffs must be ordered temptation > reward > punishment > sucker",
+            ));
+        }
+
+        let (Ok(rewards), Ok(exploits)) = (
+            self.reward.checked_mul(Uint128::new(2)),
+            self.temptation.checked_add(self.sucker),
+        ) else {
+            return Err(String::from("payoffs are too large"));
+        };
+        if rewards <= exploits {
+            return Err(String::from(
+                "twice the reward must be greater than the temptation and sucker payoffs",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Payoffs of the two players of a round given their choices
+    pub fn of(
+        &self,
+        choices: (&CooperationDilemmaChoices, &CooperationDilemmaChoices),
+    ) -> (Uint128, Uint128) {
+        use CooperationDilemmaChoices::*;
+        match choices {
+            (Cooperate, Cooperate) => (self.reward, self.reward),
+            (Cooperate, Defect) => (self.sucker, self.temptation),
+            (Defect, Cooperate) => (self.temptation, self.sucker),
+            (Defect, Defect) => (self.punishment, self.punishment),
+        }
+    }
+}
+
 #[cw_serde]
 pub enum CooperationDilemmaChoices {
     Cooperate,
@@ -28,73 +104,58 @@ impl TryFrom<String> for CooperationDilemmaChoices {
     }
 }
 
+impl CooperationDilemma {
+    /// Payoffs a game is played with, the contract's unless the game overrides them
+    pub fn payoffs(deps: Deps, config: &DilemmaConfig) -> StdResult<Payoffs> {
+        match &config.payoffs {
+            Some(payoffs) => Ok(payoffs.clone()),
+            None => PAYOFFS.load(deps.storage),
+        }
+    }
+}
+
 impl GameLifecycle for CooperationDilemma {
+    type Extension = DilemmaConfig;
+
     fn is_valid_reveal_choice(value: &String) -> bool {
         let choice = CooperationDilemmaChoices::try_from(value.clone());
         choice.is_ok()
     }
 
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<DilemmaConfig>,
+    ) -> Result<(), LifecycleError> {
+        if let Some(payoffs) = &config.extension.payoffs {
+            payoffs
+                .validate()
+                .map_err(|reason| LifecycleError::InvalidGameConfig { reason })?;
+        }
+        Ok(())
+    }
+
     fn calculate_rewards_and_winners(
-        game: &mut Game,
+        deps: Deps,
+        game: &mut Game<DilemmaConfig>,
     ) -> Result<bool, LifecycleError> {
+        let payoffs = Self::payoffs(deps, &game.config.extension)?;
         let mut winnings: HashMap<Addr, Uint128> = HashMap::from([
             (game.players[0].0.clone(), Uint128::zero()),
             (game.players[1].0.clone(), Uint128::zero()),
         ]);
 
-        game.rounds.iter().for_each(|round| {
+        game.scored_rounds().for_each(|round| {
             let reveals = round.reveals.as_slice();
-            
-            match (reveals.get(0), reveals.get(1)) {
-                (Some(p1_reveal), Some(p2_reveal)) => {
-                    let p1_choice = CooperationDilemmaChoices::try_from(p1_reveal.1.clone());
-                    let p2_choice = CooperationDilemmaChoices::try_from(p2_reveal.1.clone());
-
-                    match (p1_choice, p2_choice) {
-                        // both cooperate, both get 50
-                        (Ok(CooperationDilemmaChoices::Cooperate), Ok(CooperationDilemmaChoices::Cooperate)) => {
-                            winnings.insert(
-                                reveals[0].0.clone(),
-                                winnings.get(&reveals[0].0).unwrap() + Uint128::from(50u128),
-                            );
-                            winnings.insert(
-                                reveals[1].0.clone(),
-                                winnings.get(&reveals[1].0).unwrap() + Uint128::from(50u128),
-                            );
-                        },
-                        // p1 cooperates, p2 defects, p2 gets 100
-                        (Ok(CooperationDilemmaChoices::Cooperate), Ok(CooperationDilemmaChoices::Defect)) => {
-                            winnings.insert(
-                                reveals[1].0.clone(),
-                                winnings.get(&reveals[1].0).unwrap() + Uint128::from(100u128),
-                            );
-                        },
-                        // p1 defects, p2 cooperates, p1 gets 100
-                        (Ok(CooperationDilemmaChoices::Defect), Ok(CooperationDilemmaChoices::Cooperate)) => {
-                            winnings.insert(
-                                reveals[0].0.clone(),
-                                winnings.get(&reveals[0].0).unwrap() + Uint128::from(100u128),
-                            );
-                        },
-                        // both defect, both get 0
-                        _ => {}
-                    }
-                }
-                // p1 revealed, p2 did not reveal, p1 gets 100
-                (Some(p1_reveal), None) => {
-                    winnings.insert(
-                        p1_reveal.0.clone(),
-                        winnings.get(&p1_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                // p2 revealed, p1 did not reveal, p2 gets 100
-                (None, Some(p2_reveal)) => {
-                    winnings.insert(
-                        p2_reveal.0.clone(),
-                        winnings.get(&p2_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                _ => {}
+            let [(p1, p1_reveal), (p2, p2_reveal), ..] = reveals else {
+                return;
+            };
+            let p1_choice = CooperationDilemmaChoices::try_from(p1_reveal.clone());
+            let p2_choice = CooperationDilemmaChoices::try_from(p2_reveal.clone());
+
+            if let (Ok(p1_choice), Ok(p2_choice)) = (p1_choice, p2_choice) {
+                let (p1_payoff, p2_payoff) = payoffs.of((&p1_choice, &p2_choice));
+                *winnings.entry(p1.clone()).or_default() += p1_payoff;
+                *winnings.entry(p2.clone()).or_default() += p2_payoff;
             }
         });
 
@@ -1,15 +1,20 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
-use cw_game_lifecycle::msg::{
-    ExecuteMsg as LifecycleExecuteMsg, InstantiateMsg as LifecycleInstantiateMsg,
-    QueryMsg as LifecycleQueryMsg,
-};
+use cosmwasm_std::{Addr, Binary};
+use cw_game_lifecycle::msg::InstantiateMsg as LifecycleInstantiateMsg;
+use cw_game_lifecycle::state::RatingConfig;
+use cw_game_lifecycle::{lifecycle_execute, lifecycle_query};
+
+use crate::dilemma::{DilemmaConfig, Payoffs};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub base_url: String,
     pub image_url: String,
     pub token_contract: Addr,
+    pub rating_config: Option<RatingConfig>,
+    pub identity_contract: Option<Addr>,
+    pub attestation_pubkey: Option<Binary>,
+    pub payoffs: Option<Payoffs>, // defaults to `Payoffs::default()`, games can override them
 }
 
 impl From<InstantiateMsg> for LifecycleInstantiateMsg {
@@ -18,43 +23,25 @@ impl From<InstantiateMsg> for LifecycleInstantiateMsg {
             base_url: msg.base_url,
             image_url: msg.image_url,
             token_contract: msg.token_contract,
+            rating_config: msg.rating_config,
+            identity_contract: msg.identity_contract,
+            attestation_pubkey: msg.attestation_pubkey,
         }
     }
 }
 
+#[lifecycle_execute(DilemmaConfig)]
 #[cw_serde]
 pub enum ExecuteMsg {
-    Lifecycle(LifecycleExecuteMsg),
+    // ... custom execute messages specific to this contract
 }
 
+#[lifecycle_query(DilemmaConfig)]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    #[returns(cw_game_lifecycle::state::Game)]
-    GetGame { game_id: u64 },
-    #[returns(cw_game_lifecycle::state::Leaderboard)]
-    GetLeaderboard {},
-    #[returns(cw_game_lifecycle::state::GameRound)]
-    GetCurrentRound { game_id: u64 },
-    #[returns(cw_game_lifecycle::state::GameStatus)]
-    GetGameStatus { game_id: u64 },
-    #[returns(u64)]
-    GetGamesCount {},
-}
-
-// Even though the query messages are the same, we need to implement this because the
-// the return types cannot be inherited easily and therefore each query must be specified
-// independently. While this is not a perfect solution, it is a simple one.
-impl From<QueryMsg> for LifecycleQueryMsg {
-    fn from(msg: QueryMsg) -> Self {
-        match msg {
-            QueryMsg::GetGame { game_id } => LifecycleQueryMsg::GetGame { game_id },
-            QueryMsg::GetLeaderboard {} => LifecycleQueryMsg::GetLeaderboard {},
-            QueryMsg::GetCurrentRound { game_id } => LifecycleQueryMsg::GetCurrentRound { game_id },
-            QueryMsg::GetGameStatus { game_id } => LifecycleQueryMsg::GetGameStatus { game_id },
-            QueryMsg::GetGamesCount {} => LifecycleQueryMsg::GetGamesCount {},
-        }
-    }
+    #[returns(Payoffs)] // payoffs of the game if set, the contract's otherwise
+    GetPayoffs { game_id: Option<u64> },
 }
 
 #[cw_serde]
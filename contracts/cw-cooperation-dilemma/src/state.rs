@@ -1 +1,5 @@
+use cw_storage_plus::Item;
 
+use crate::dilemma::Payoffs;
+
+pub const PAYOFFS: Item<Payoffs> = Item::new("payoffs"); // contract wide payoffs, set at instantiate
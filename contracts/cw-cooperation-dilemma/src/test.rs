@@ -5,6 +5,8 @@ mod tests {
     use sha2::{Sha256, Digest};
     use hex;
 
+    use crate::dilemma::{DilemmaConfig, Payoffs};
+
     ///
     /// A wrapper around the code ID of the P2E token contract
     ///
@@ -114,6 +116,10 @@ mod tests {
                 base_url: "https://example.com".to_string(),
                 image_url: "https://example.com/image.png".to_string(),
                 token_contract: p2e_contract.addr(),
+                rating_config: None,
+                identity_contract: None,
+                attestation_pubkey: None,
+                payoffs: None,
             };
             app.instantiate_contract(code_id.0, sender, &init_msg, &[], label, None)
                 .map(Self::from)
@@ -176,6 +182,7 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
             });
             app.execute_contract(p.clone(), game_contract.addr(), &msg, &[]).unwrap();
         });
@@ -217,7 +224,7 @@ mod tests {
 
         assert_eq!(counter, 1);
 
-        let game: cw_game_lifecycle::state::Game = app
+        let game: cw_game_lifecycle::state::Game<DilemmaConfig> = app
             .wrap()
             .query_wasm_smart(cooperation_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
             .unwrap();
@@ -259,6 +266,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), cooperation_game_contract.addr(), &msg, &[])
@@ -268,6 +276,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), cooperation_game_contract.addr(), &msg, &[])
@@ -317,6 +326,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), cooperation_game_contract.addr(), &msg, &[])
@@ -326,6 +336,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), cooperation_game_contract.addr(), &msg, &[])
@@ -367,6 +378,7 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
             });
             app.execute_contract(p.clone(), cooperation_game_contract.addr(), &msg, &[]).unwrap();
         });
@@ -375,6 +387,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: p3.to_string(),
+            attestation: None,
         });
         let res = app.execute_contract(p3.clone(), cooperation_game_contract.addr(), &msg, &[]);
         assert!(res.is_err());
@@ -548,7 +561,7 @@ mod tests {
             app.execute_contract(p2.clone(), cooperation_game_contract.addr(), &msg, &[]).unwrap();
 
             // check game status, should not be finished yet
-            let game_details: cw_game_lifecycle::state::Game = app
+            let game_details: cw_game_lifecycle::state::Game<DilemmaConfig> = app
                 .wrap()
                 .query_wasm_smart(cooperation_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
                 .unwrap();
@@ -586,11 +599,200 @@ mod tests {
         }).unwrap();
         assert_eq!(balance.balance, Uint128::new(150));
 
-        let game_details: cw_game_lifecycle::state::Game = app
+        let game_details: cw_game_lifecycle::state::Game<DilemmaConfig> = app
             .wrap()
             .query_wasm_smart(cooperation_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
             .unwrap();
         assert_eq!(game_details.status, cw_game_lifecycle::state::GameStatus::Ended);
         assert_eq!(game_details.current_round, 3);
     }
+
+    fn play_round(app: &mut App, game_contract: &Addr, choices: &[(&Addr, &str)]) {
+        for (player, choice) in choices {
+            let mut hasher = Sha256::new();
+            hasher.update(choice.as_bytes());
+            hasher.update(123u64.to_be_bytes());
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hex::encode(hasher.finalize()),
+                amount: None,
+            });
+            app.execute_contract((*player).clone(), game_contract.clone(), &msg, &[]).unwrap();
+        }
+        for (player, choice) in choices {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: choice.to_string(),
+                nonce: 123,
+            });
+            app.execute_contract((*player).clone(), game_contract.clone(), &msg, &[]).unwrap();
+        }
+    }
+
+    #[test]
+    fn dilemma_payoffs_are_configurable() {
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p2e_contract = P2ETokenCodeId::store_code(&mut app)
+            .instantiate(&mut app, owner.clone(), "test", None)
+            .unwrap();
+        let code_id = CooperationGameCodeId::store_code(&mut app).0;
+        let payoffs = |temptation: u128, reward: u128, punishment: u128, sucker: u128| Payoffs {
+            temptation: Uint128::new(temptation),
+            reward: Uint128::new(reward),
+            punishment: Uint128::new(punishment),
+            sucker: Uint128::new(sucker),
+        };
+        let init_msg = |payoffs: Payoffs| crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract: p2e_contract.addr(),
+            rating_config: None,
+            identity_contract: None,
+            attestation_pubkey: None,
+            payoffs: Some(payoffs),
+        };
+
+        // mutual defection must pay more than being the sucker
+        let err = app
+            .instantiate_contract(code_id, owner.clone(), &init_msg(payoffs(5, 3, 0, 0)), &[], "test", None)
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::InvalidPayoffs {
+                reason: "payoffs must be ordered temptation > reward > punishment > sucker".to_string(),
+            }
+            .to_string()
+        );
+
+        let game_contract = app
+            .instantiate_contract(code_id, owner.clone(), &init_msg(payoffs(5, 3, 1, 0)), &[], "test", None)
+            .unwrap();
+        let msg = cw_p2e::msg::ExecuteMsg::AuthorizeRewardsIssuer { address: game_contract.to_string() };
+        app.execute_contract(owner.clone(), p2e_contract.addr(), &msg, &[]).unwrap();
+        let contract_payoffs: Payoffs = app
+            .wrap()
+            .query_wasm_smart(game_contract.clone(), &crate::msg::QueryMsg::GetPayoffs { game_id: None })
+            .unwrap();
+        assert_eq!(contract_payoffs, payoffs(5, 3, 1, 0));
+
+        // games can override the contract's payoffs, as long as they still make a dilemma
+        let create_game = |payoffs: Payoffs| {
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config: cw_game_lifecycle::state::GameConfig {
+                    max_players: Some(2),
+                    max_rounds: 1,
+                    extension: DilemmaConfig { payoffs: Some(payoffs) },
+                    ..Default::default()
+                },
+            })
+        };
+        let err = app
+            .execute_contract(p1.clone(), game_contract.clone(), &create_game(payoffs(100, 50, 10, 0)), &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "twice the reward must be greater than the temptation and sucker payoffs"
+                    .to_string(),
+            })
+            .to_string()
+        );
+
+        // payoffs too large to compare are rejected rather than overflowing
+        let err = app
+            .execute_contract(p1.clone(), game_contract.clone(), &create_game(payoffs(u128::MAX, u128::MAX - 1, 1, 0)), &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "payoffs are too large".to_string(),
+            })
+            .to_string()
+        );
+        app.execute_contract(p1.clone(), game_contract.clone(), &create_game(payoffs(200, 120, 40, 10)), &[])
+            .unwrap();
+        let game_payoffs: Payoffs = app
+            .wrap()
+            .query_wasm_smart(game_contract.clone(), &crate::msg::QueryMsg::GetPayoffs { game_id: Some(0) })
+            .unwrap();
+        assert_eq!(game_payoffs, payoffs(200, 120, 40, 10));
+
+        for player in [&p1, &p2] {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id: 0,
+                telegram_id: player.to_string(),
+                attestation: None,
+            });
+            app.execute_contract(player.clone(), game_contract.clone(), &msg, &[]).unwrap();
+        }
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id: 0 });
+        app.execute_contract(p1.clone(), game_contract.clone(), &msg, &[]).unwrap();
+
+        // player 1 defects against a cooperator: temptation for them, sucker for player 2
+        play_round(&mut app, &game_contract, &[(&p1, "defect"), (&p2, "cooperate")]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 });
+        app.execute_contract(p1.clone(), game_contract.clone(), &msg, &[]).unwrap();
+
+        for (player, expected) in [(&p1, 200), (&p2, 10)] {
+            let balance: cw20::BalanceResponse = app.wrap().query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance {
+                address: player.to_string(),
+            }).unwrap();
+            assert_eq!(balance.balance, Uint128::new(expected));
+        }
+    }
+
+    #[test]
+    fn dilemma_default_payoffs() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, cooperation_game_contract) = setup_contracts(&mut app, None);
+
+        // without payoffs at instantiate the contract plays the 90/50/10/0 matrix, replacing the
+        // former hardcoded 100/50/0/0 one that was not a true dilemma
+        let payoffs: Payoffs = app
+            .wrap()
+            .query_wasm_smart(cooperation_game_contract.addr(), &crate::msg::QueryMsg::GetPayoffs { game_id: None })
+            .unwrap();
+        assert_eq!(payoffs, Payoffs::default());
+        assert_eq!(
+            payoffs,
+            Payoffs {
+                temptation: Uint128::new(90),
+                reward: Uint128::new(50),
+                punishment: Uint128::new(10),
+                sucker: Uint128::zero(),
+            }
+        );
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+            config: cw_game_lifecycle::state::GameConfig {
+                max_players: Some(2),
+                max_rounds: 3,
+                ..Default::default()
+            },
+        });
+        app.execute_contract(p1.clone(), cooperation_game_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &cooperation_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame { game_id: 0 });
+        app.execute_contract(p1.clone(), cooperation_game_contract.addr(), &msg, &[]).unwrap();
+
+        // reward for both, then temptation for player 1 and sucker for player 2, then punishment
+        // for both
+        play_round(&mut app, &cooperation_game_contract.addr(), &[(&p1, "cooperate"), (&p2, "cooperate")]);
+        play_round(&mut app, &cooperation_game_contract.addr(), &[(&p1, "defect"), (&p2, "cooperate")]);
+        play_round(&mut app, &cooperation_game_contract.addr(), &[(&p1, "defect"), (&p2, "defect")]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 });
+        app.execute_contract(p1.clone(), cooperation_game_contract.addr(), &msg, &[]).unwrap();
+
+        for (player, expected) in [(&p1, 50 + 90 + 10), (&p2, 50 + 10)] {
+            let balance: cw20::BalanceResponse = app.wrap().query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance {
+                address: player.to_string(),
+            }).unwrap();
+            assert_eq!(balance.balance, Uint128::new(expected));
+        }
+    }
 }
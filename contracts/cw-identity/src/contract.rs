@@ -0,0 +1,165 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{ADDRESSES, ADMINS, OWNER, TELEGRAM_IDS};
+
+const CONTRACT_NAME: &str = "crates.io:cw-identity";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    OWNER.save(deps.storage, &info.sender)?;
+    let admins = validate_addresses(deps.as_ref(), msg.admins)?;
+    ADMINS.save(deps.storage, &admins)?;
+
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Bind {
+            telegram_id,
+            address,
+        } => execute_bind(deps, info, telegram_id, address),
+        ExecuteMsg::Rebind {
+            telegram_id,
+            address,
+        } => execute_rebind(deps, info, telegram_id, address),
+        ExecuteMsg::Unbind { telegram_id } => execute_unbind(deps, info, telegram_id),
+        ExecuteMsg::UpdateAdmins { admins } => execute_update_admins(deps, info, admins),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetAddress { telegram_id } => {
+            to_json_binary(&ADDRESSES.may_load(deps.storage, telegram_id)?)
+        }
+        QueryMsg::GetTelegramId { address } => {
+            to_json_binary(&TELEGRAM_IDS.may_load(deps.storage, &address)?)
+        }
+        QueryMsg::GetAdmins {} => to_json_binary(&ADMINS.load(deps.storage)?),
+    }
+}
+
+fn execute_bind(
+    deps: DepsMut,
+    info: MessageInfo,
+    telegram_id: String,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let address = deps.api.addr_validate(address.as_str())?;
+
+    if ADDRESSES.has(deps.storage, telegram_id.clone()) {
+        return Err(ContractError::TelegramIdAlreadyBound { telegram_id });
+    } else if TELEGRAM_IDS.has(deps.storage, &address) {
+        return Err(ContractError::AddressAlreadyBound { address });
+    }
+
+    ADDRESSES.save(deps.storage, telegram_id.clone(), &address)?;
+    TELEGRAM_IDS.save(deps.storage, &address, &telegram_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "bind")
+        .add_attribute("telegram_id", telegram_id)
+        .add_attribute("address", address.to_string()))
+}
+
+fn execute_rebind(
+    deps: DepsMut,
+    info: MessageInfo,
+    telegram_id: String,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let address = deps.api.addr_validate(address.as_str())?;
+
+    let previous_address = ADDRESSES
+        .may_load(deps.storage, telegram_id.clone())?
+        .ok_or(ContractError::TelegramIdNotBound {
+            telegram_id: telegram_id.clone(),
+        })?;
+    if previous_address != address && TELEGRAM_IDS.has(deps.storage, &address) {
+        // the new address must be released first to keep the binding one to one
+        return Err(ContractError::AddressAlreadyBound { address });
+    }
+
+    TELEGRAM_IDS.remove(deps.storage, &previous_address);
+    ADDRESSES.save(deps.storage, telegram_id.clone(), &address)?;
+    TELEGRAM_IDS.save(deps.storage, &address, &telegram_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "rebind")
+        .add_attribute("telegram_id", telegram_id)
+        .add_attribute("previous_address", previous_address.to_string())
+        .add_attribute("address", address.to_string()))
+}
+
+fn execute_unbind(
+    deps: DepsMut,
+    info: MessageInfo,
+    telegram_id: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+
+    let address = ADDRESSES
+        .may_load(deps.storage, telegram_id.clone())?
+        .ok_or(ContractError::TelegramIdNotBound {
+            telegram_id: telegram_id.clone(),
+        })?;
+    ADDRESSES.remove(deps.storage, telegram_id.clone());
+    TELEGRAM_IDS.remove(deps.storage, &address);
+
+    Ok(Response::new()
+        .add_attribute("action", "unbind")
+        .add_attribute("telegram_id", telegram_id)
+        .add_attribute("address", address.to_string()))
+}
+
+fn execute_update_admins(
+    deps: DepsMut,
+    info: MessageInfo,
+    admins: Vec<Addr>,
+) -> Result<Response, ContractError> {
+    if OWNER.load(deps.storage)? != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let admins = validate_addresses(deps.as_ref(), admins)?;
+    ADMINS.save(deps.storage, &admins)?;
+
+    Ok(Response::new().add_attribute("action", "update_admins"))
+}
+
+fn assert_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !ADMINS.load(deps.storage)?.contains(sender) && OWNER.load(deps.storage)? != *sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn validate_addresses(deps: Deps, addresses: Vec<Addr>) -> StdResult<Vec<Addr>> {
+    addresses
+        .into_iter()
+        .map(|a| deps.api.addr_validate(a.as_str()))
+        .collect()
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Telegram ID {telegram_id} is already bound to an address")]
+    TelegramIdAlreadyBound { telegram_id: String },
+
+    #[error("Address {address} is already bound to a Telegram ID")]
+    AddressAlreadyBound { address: Addr },
+
+    #[error("Telegram ID {telegram_id} is not bound to any address")]
+    TelegramIdNotBound { telegram_id: String },
+}
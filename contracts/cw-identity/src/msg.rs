@@ -0,0 +1,40 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admins: Vec<Addr>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Binds a Telegram ID to an address, neither of which can already be bound (admins only)
+    Bind {
+        telegram_id: String,
+        address: Addr,
+    },
+    // Moves an already bound Telegram ID to a new address (admins only)
+    Rebind {
+        telegram_id: String,
+        address: Addr,
+    },
+    // Removes the binding of a Telegram ID (admins only)
+    Unbind {
+        telegram_id: String,
+    },
+    // Replaces the list of admins (owner only)
+    UpdateAdmins {
+        admins: Vec<Addr>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Option<Addr>)]
+    GetAddress { telegram_id: String },
+    #[returns(Option<String>)]
+    GetTelegramId { address: Addr },
+    #[returns(Vec<Addr>)]
+    GetAdmins {},
+}
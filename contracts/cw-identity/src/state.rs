@@ -0,0 +1,7 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const ADMINS: Item<Vec<Addr>> = Item::new("admins");
+pub const ADDRESSES: Map<String, Addr> = Map::new("addresses"); // (Telegram_id, address)
+pub const TELEGRAM_IDS: Map<&Addr, String> = Map::new("telegram_ids"); // (address, Telegram_id)
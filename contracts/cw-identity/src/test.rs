@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{App, ContractWrapper, Executor};
+
+    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+    use crate::ContractError;
+
+    fn setup_contract(app: &mut App, admin: &Addr) -> Addr {
+        let owner = app.api().addr_make("owner");
+        let code_id = app.store_code(Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )));
+        let msg = InstantiateMsg {
+            admins: vec![admin.clone()],
+        };
+        app.instantiate_contract(code_id, owner, &msg, &[], "identity", None)
+            .unwrap()
+    }
+
+    fn query_address(app: &App, contract: &Addr, telegram_id: &str) -> Option<Addr> {
+        app.wrap()
+            .query_wasm_smart(
+                contract,
+                &QueryMsg::GetAddress {
+                    telegram_id: telegram_id.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn query_telegram_id(app: &App, contract: &Addr, address: &Addr) -> Option<String> {
+        app.wrap()
+            .query_wasm_smart(
+                contract,
+                &QueryMsg::GetTelegramId {
+                    address: address.clone(),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn identity_bind_is_one_to_one() {
+        let mut app = App::default();
+        let admin = app.api().addr_make("admin");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let contract = setup_contract(&mut app, &admin);
+
+        let msg = ExecuteMsg::Bind {
+            telegram_id: "1234567890".to_string(),
+            address: p1.clone(),
+        };
+        app.execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap();
+        assert_eq!(query_address(&app, &contract, "1234567890"), Some(p1.clone()));
+        assert_eq!(
+            query_telegram_id(&app, &contract, &p1),
+            Some("1234567890".to_string())
+        );
+
+        // the same telegram ID cannot be claimed by another address
+        let msg = ExecuteMsg::Bind {
+            telegram_id: "1234567890".to_string(),
+            address: p2.clone(),
+        };
+        let err = app
+            .execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::TelegramIdAlreadyBound {
+                telegram_id: "1234567890".to_string()
+            }
+            .to_string()
+        );
+
+        // the same address cannot claim a second telegram ID
+        let msg = ExecuteMsg::Bind {
+            telegram_id: "0987654321".to_string(),
+            address: p1.clone(),
+        };
+        let err = app
+            .execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::AddressAlreadyBound { address: p1 }.to_string()
+        );
+    }
+
+    #[test]
+    fn identity_only_admins_can_bind() {
+        let mut app = App::default();
+        let admin = app.api().addr_make("admin");
+        let p1 = app.api().addr_make("player_1");
+        let contract = setup_contract(&mut app, &admin);
+
+        let msg = ExecuteMsg::Bind {
+            telegram_id: "1234567890".to_string(),
+            address: p1.clone(),
+        };
+        let err = app
+            .execute_contract(p1.clone(), contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::Unauthorized {}.to_string()
+        );
+        assert_eq!(query_address(&app, &contract, "1234567890"), None);
+    }
+
+    #[test]
+    fn identity_rebind_and_unbind() {
+        let mut app = App::default();
+        let admin = app.api().addr_make("admin");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let contract = setup_contract(&mut app, &admin);
+
+        let msg = ExecuteMsg::Bind {
+            telegram_id: "1234567890".to_string(),
+            address: p1.clone(),
+        };
+        app.execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap();
+
+        // the admin moves the telegram ID to a new address, releasing the old one
+        let msg = ExecuteMsg::Rebind {
+            telegram_id: "1234567890".to_string(),
+            address: p2.clone(),
+        };
+        app.execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap();
+        assert_eq!(query_address(&app, &contract, "1234567890"), Some(p2.clone()));
+        assert_eq!(query_telegram_id(&app, &contract, &p1), None);
+        assert_eq!(
+            query_telegram_id(&app, &contract, &p2),
+            Some("1234567890".to_string())
+        );
+
+        let msg = ExecuteMsg::Unbind {
+            telegram_id: "1234567890".to_string(),
+        };
+        app.execute_contract(admin.clone(), contract.clone(), &msg, &[])
+            .unwrap();
+        assert_eq!(query_address(&app, &contract, "1234567890"), None);
+        assert_eq!(query_telegram_id(&app, &contract, &p2), None);
+    }
+}
@@ -0,0 +1,19 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    GameLifecycle(#[from] cw_game_lifecycle::error::ContractError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid matrix: {reason}")]
+    InvalidMatrix { reason: String },
+    // Add any other custom errors you like here.
+    // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
+}
@@ -0,0 +1,9 @@
+pub mod contract;
+mod error;
+pub mod helpers;
+pub mod matrix;
+pub mod msg;
+pub mod state;
+mod test;
+
+pub use crate::error::ContractError;
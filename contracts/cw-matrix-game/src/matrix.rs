@@ -0,0 +1,118 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Empty, StdResult, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{AbsencePolicy, Game, GameConfig},
+    ContractError as LifecycleError,
+};
+use std::collections::HashMap;
+
+use crate::state::{Matrix, Payoff, MATRIX};
+
+#[cw_serde]
+pub struct MatrixGame;
+
+impl Matrix {
+    /// Checks every role has distinct actions and the payoff table matches them
+    pub fn validate(&self) -> Result<(), String> {
+        for actions in [&self.row_actions, &self.column_actions] {
+            if actions.is_empty() {
+                return Err(String::from("every role needs at least one action"));
+            } else if actions.iter().enumerate().any(|(i, action)| actions[..i].contains(action)) {
+                return Err(String::from("action names must be unique within a role"));
+            }
+        }
+
+        if self.payoffs.len() != self.row_actions.len()
+            || self.payoffs.iter().any(|row| row.len() != self.column_actions.len())
+        {
+            return Err(String::from(
+                "payoffs must have a row per row action and a column per column action",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Actions of the player at the given position in the game's players
+    fn actions(&self, position: usize) -> &[String] {
+        match position {
+            0 => &self.row_actions,
+            _ => &self.column_actions,
+        }
+    }
+
+    /// Cell of the table for the actions played, if both are known
+    fn payoff(&self, row_action: &str, column_action: &str) -> Option<&Payoff> {
+        let row = self.row_actions.iter().position(|a| a == row_action)?;
+        let column = self.column_actions.iter().position(|a| a == column_action)?;
+        self.payoffs.get(row)?.get(column)
+    }
+}
+
+impl GameLifecycle for MatrixGame {
+    type Extension = Empty;
+
+    fn is_valid_move(
+        deps: Deps,
+        game: &Game,
+        player: &Addr,
+        value: &String,
+    ) -> StdResult<bool> {
+        // each role has its own actions, see `Matrix`
+        let matrix = MATRIX.load(deps.storage)?;
+        let position = game.players.iter().position(|p| p.0 == *player);
+        Ok(position.is_some_and(|position| matrix.actions(position).contains(value)))
+    }
+
+    fn validate_config(deps: Deps, config: &GameConfig) -> Result<(), LifecycleError> {
+        if config.min_players != 2 || config.max_players != Some(2) {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("matrix games are played by two players"),
+            });
+        }
+
+        // absent players can hold either role, so the default move must be an action of both
+        if let AbsencePolicy::DefaultMove { value } = &config.absence_policy {
+            let matrix = MATRIX.load(deps.storage)?;
+            if !matrix.row_actions.contains(value) || !matrix.column_actions.contains(value) {
+                return Err(LifecycleError::InvalidDefaultMove {
+                    value: value.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn calculate_rewards_and_winners(
+        deps: Deps,
+        game: &mut Game,
+    ) -> Result<bool, LifecycleError> {
+        let matrix = MATRIX.load(deps.storage)?;
+        let mut winnings: HashMap<Addr, Uint128> = game
+            .players
+            .iter()
+            .map(|(player, _)| (player.clone(), Uint128::zero()))
+            .collect();
+
+        if let [(row_player, _), (column_player, _)] = game.players.as_slice() {
+            for round in game.scored_rounds() {
+                let action_of = |player: &Addr| {
+                    round.reveals.iter().find(|r| r.0 == *player).map(|r| r.1.as_str())
+                };
+                let payoff = action_of(row_player)
+                    .zip(action_of(column_player))
+                    .and_then(|(row_action, column_action)| {
+                        matrix.payoff(row_action, column_action)
+                    });
+
+                if let Some(payoff) = payoff {
+                    *winnings.entry(row_player.clone()).or_default() += payoff.row;
+                    *winnings.entry(column_player.clone()).or_default() += payoff.column;
+                }
+            }
+        }
+
+        game.scores = winnings;
+        Ok(true)
+    }
+}
@@ -0,0 +1,48 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary};
+use cw_game_lifecycle::msg::InstantiateMsg as LifecycleInstantiateMsg;
+use cw_game_lifecycle::state::RatingConfig;
+use cw_game_lifecycle::{lifecycle_execute, lifecycle_query};
+
+use crate::state::Matrix;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub base_url: String,
+    pub image_url: String,
+    pub token_contract: Addr,
+    pub rating_config: Option<RatingConfig>,
+    pub identity_contract: Option<Addr>,
+    pub attestation_pubkey: Option<Binary>,
+    pub matrix: Matrix, // actions of each role and their payoffs, fixed for every game
+}
+
+impl From<InstantiateMsg> for LifecycleInstantiateMsg {
+    fn from(msg: InstantiateMsg) -> Self {
+        LifecycleInstantiateMsg {
+            base_url: msg.base_url,
+            image_url: msg.image_url,
+            token_contract: msg.token_contract,
+            rating_config: msg.rating_config,
+            identity_contract: msg.identity_contract,
+            attestation_pubkey: msg.attestation_pubkey,
+        }
+    }
+}
+
+#[lifecycle_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    // ... custom execute messages specific to this contract
+}
+
+#[lifecycle_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Matrix)]
+    GetMatrix {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,21 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+use cw_storage_plus::Item;
+
+pub const MATRIX: Item<Matrix> = Item::new("matrix");
+
+/// Actions and payoffs of the game, fixed at instantiate. The first player to join a game plays
+/// the rows and the second the columns.
+#[cw_serde]
+pub struct Matrix {
+    pub row_actions: Vec<String>,
+    pub column_actions: Vec<String>,
+    pub payoffs: Vec<Vec<Payoff>>, // one row per row action, one column per column action
+}
+
+/// Payoffs of a cell of the matrix to the row and the column player
+#[cw_serde]
+pub struct Payoff {
+    pub row: Uint128,
+    pub column: Uint128,
+}
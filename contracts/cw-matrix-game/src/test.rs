@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Empty, Uint128};
+    use cw_game_lifecycle::state::{AbsencePolicy, GameConfig};
+    use cw_game_lifecycle::test_utils::{
+        self, assert_lifecycle_error, balance_of, commit, create_game, execute_lifecycle, game_config,
+        play_round, reveal, start_game,
+    };
+    use cw_game_lifecycle::ContractError as LifecycleError;
+    use cw_multi_test::{App, Contract, ContractWrapper};
+
+    use crate::state::{Matrix, Payoff};
+
+    fn matrix() -> Matrix {
+        let payoffs = |cells: &[(u128, u128)]| {
+            cells
+                .iter()
+                .map(|(row, column)| Payoff {
+                    row: Uint128::new(*row),
+                    column: Uint128::new(*column),
+                })
+                .collect()
+        };
+        Matrix {
+            row_actions: vec!["up".to_string(), "down".to_string()],
+            column_actions: vec!["left".to_string(), "middle".to_string(), "right".to_string()],
+            payoffs: vec![
+                payoffs(&[(30, 10), (0, 0), (50, 20)]),
+                payoffs(&[(10, 40), (20, 20), (0, 60)]),
+            ],
+        }
+    }
+
+    fn init_msg(token_contract: Addr, matrix: Matrix) -> crate::msg::InstantiateMsg {
+        crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract,
+            rating_config: None,
+            identity_contract: None,
+            attestation_pubkey: None,
+            matrix,
+        }
+    }
+
+    fn matrix_game() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        ))
+    }
+
+    #[test]
+    fn matrix_game_pays_the_cell_of_the_actions_played() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, matrix_game(), |p2e_contract| init_msg(p2e_contract, matrix()));
+
+        let queried: Matrix = app
+            .wrap()
+            .query_wasm_smart(game_contract.clone(), &crate::msg::QueryMsg::GetMatrix {})
+            .unwrap();
+        assert_eq!(queried, matrix());
+
+        // player 1 joins first and plays the rows, player 2 plays the columns
+        start_game(&mut app, &game_contract, &[&p1, &p2], game_config(2, 2, Empty {}));
+        play_round(&mut app, &game_contract, &[(&p1, "up"), (&p2, "right")]);
+        // roles do not depend on the order of the reveals
+        play_round(&mut app, &game_contract, &[(&p2, "middle"), (&p1, "down")]);
+
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        assert_eq!(balance_of(&app, &p2e_contract, &p1), Uint128::new(50 + 20));
+        assert_eq!(balance_of(&app, &p2e_contract, &p2), Uint128::new(20 + 20));
+    }
+
+    #[test]
+    fn matrix_game_validates_matrices_and_actions() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, matrix_game(), |p2e_contract| init_msg(p2e_contract, matrix()));
+
+        let mut duplicate_actions = matrix();
+        duplicate_actions.column_actions[2] = "left".to_string();
+        let mut missing_row = matrix();
+        missing_row.payoffs.pop();
+        for (matrix, reason) in [
+            (duplicate_actions, "action names must be unique within a role"),
+            (missing_row, "payoffs must have a row per row action and a column per column action"),
+        ] {
+            let err = test_utils::instantiate_game(&mut app, matrix_game(), &init_msg(p2e_contract.clone(), matrix))
+                .unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                crate::ContractError::InvalidMatrix { reason: reason.to_string() }.to_string()
+            );
+        }
+
+        // the default move is played by absent players of either role
+        let config = GameConfig {
+            absence_policy: AbsencePolicy::DefaultMove { value: "up".to_string() },
+            ..game_config(2, 1, Empty {})
+        };
+        let err = create_game(&mut app, &game_contract, &p1, config).unwrap_err();
+        assert_lifecycle_error(err, LifecycleError::InvalidDefaultMove { value: "up".to_string() });
+
+        // the row player cannot play a column action
+        start_game(&mut app, &game_contract, &[&p1, &p2], game_config(2, 1, Empty {}));
+        commit(&mut app, &game_contract, &p1, "left").unwrap();
+        commit(&mut app, &game_contract, &p2, "left").unwrap();
+        let err = reveal(&mut app, &game_contract, &p1, "left").unwrap_err();
+        assert_lifecycle_error(err, LifecycleError::InvalidRevealChoice { game_id: 0, round: 1 });
+        reveal(&mut app, &game_contract, &p2, "left").unwrap();
+    }
+}
@@ -2,6 +2,8 @@ pub mod contract;
 mod error;
 pub mod helpers;
 pub mod msg;
+pub mod public_goods;
 pub mod state;
+mod test;
 
 pub use crate::error::ContractError;
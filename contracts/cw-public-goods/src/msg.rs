@@ -1,11 +1,54 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary};
+use cw_game_lifecycle::msg::InstantiateMsg as LifecycleInstantiateMsg;
+use cw_game_lifecycle::state::RatingConfig;
+use cw_game_lifecycle::{lifecycle_execute, lifecycle_query};
+
+use crate::public_goods::PublicGoodsConfig;
 
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    pub base_url: String,
+    pub image_url: String,
+    pub token_contract: Addr,
+    pub rating_config: Option<RatingConfig>,
+    pub identity_contract: Option<Addr>,
+    pub attestation_pubkey: Option<Binary>,
+}
+
+impl From<InstantiateMsg> for LifecycleInstantiateMsg {
+    fn from(msg: InstantiateMsg) -> Self {
+        LifecycleInstantiateMsg {
+            base_url: msg.base_url,
+            image_url: msg.image_url,
+            token_contract: msg.token_contract,
+            rating_config: msg.rating_config,
+            identity_contract: msg.identity_contract,
+            attestation_pubkey: msg.attestation_pubkey,
+        }
+    }
+}
 
+#[lifecycle_execute(PublicGoodsConfig)]
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub enum ExecuteMsg {
+    // ... custom execute messages specific to this contract
+}
 
+#[lifecycle_query(PublicGoodsConfig)]
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum QueryMsg {}
+pub enum QueryMsg {
+    #[returns(StageResponse)] // contribution round of the game and whether it is punishing
+    GetStage { game_id: u64 },
+}
+
+#[cw_serde]
+pub struct StageResponse {
+    pub contribution_round: u8, // 0 until the game starts
+    pub contribution_rounds: u8, // `max_rounds` without punishment and half of it with
+    pub punishment: bool, // whether the current round punishes the contribution round before it
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
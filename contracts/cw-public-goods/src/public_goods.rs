@@ -0,0 +1,261 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{AbsencePolicy, Game, GameConfig, GameRound},
+    ContractError as LifecycleError,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::msg::StageResponse;
+
+#[cw_serde]
+pub struct PublicGoods;
+
+/// Public goods specific parameters of a game
+#[cw_serde]
+pub struct PublicGoodsConfig {
+    pub endowment: Uint128, // tokens each player can contribute from every round
+    pub multiplier: Decimal, // applied to the pot before it is split between the players
+    // adds a punishment stage after every contribution round. Both stages are lifecycle rounds,
+    // odd rounds collect contributions and even rounds punish, so `max_rounds` and the current
+    // round count twice the contribution rounds. `GetStage` gives the contribution round
+    pub punishment: Option<Punishment>,
+}
+
+/// Ratio of the punishment stage: spending `cost` points reduces the target's payoff by `impact`
+#[cw_serde]
+pub struct Punishment {
+    pub cost: Uint128,
+    pub impact: Uint128,
+}
+
+impl Default for PublicGoodsConfig {
+    fn default() -> Self {
+        Self {
+            endowment: Uint128::new(100),
+            multiplier: Decimal::percent(150),
+            punishment: None,
+        }
+    }
+}
+
+impl PublicGoods {
+    /// Revealed contribution, checked against the endowment in `is_valid_move`
+    fn contribution(value: &str) -> Uint128 {
+        Uint128::from_str(value).unwrap_or_default()
+    }
+
+    fn is_valid_contribution(value: &str, endowment: Uint128) -> bool {
+        Uint128::from_str(value).is_ok_and(|contribution| contribution <= endowment)
+    }
+
+    /// Whether a round is a punishment round, with punishment every even round punishes the
+    /// contribution round before it
+    fn is_punishment_round(config: &PublicGoodsConfig, round: u8) -> bool {
+        config.punishment.is_some() && round % 2 == 0
+    }
+
+    /// Contribution round and stage a game is at, games with punishment play two lifecycle rounds
+    /// per contribution round
+    pub fn stage(game: &Game<PublicGoodsConfig>) -> StageResponse {
+        match game.config.extension.punishment {
+            Some(_) => StageResponse {
+                contribution_round: game.current_round.div_ceil(2),
+                contribution_rounds: game.config.max_rounds / 2,
+                punishment: Self::is_punishment_round(&game.config.extension, game.current_round),
+            },
+            None => StageResponse {
+                contribution_round: game.current_round,
+                contribution_rounds: game.config.max_rounds,
+                punishment: false,
+            },
+        }
+    }
+
+    /// Parses a revealed punishment, a comma separated list of `player:points` pairs. An empty
+    /// reveal punishes nobody.
+    fn punishments(value: &str) -> Option<Vec<(&str, Uint128)>> {
+        if value.is_empty() {
+            return Some(vec![]);
+        }
+        value
+            .split(',')
+            .map(|pair| {
+                let (player, points) = pair.split_once(':')?;
+                Some((player, Uint128::from_str(points).ok()?))
+            })
+            .collect()
+    }
+
+    /// Payoffs of a contribution round: the endowment minus the contribution plus an even share
+    /// of the multiplied pot
+    fn contribution_payoffs(round: &GameRound, config: &PublicGoodsConfig) -> Vec<(Addr, Uint128)> {
+        let contributions: Vec<(&Addr, Uint128)> = round
+            .reveals
+            .iter()
+            .map(|(player, value)| (player, Self::contribution(value)))
+            .collect();
+        if contributions.is_empty() {
+            return vec![];
+        }
+
+        // the multiplied pot is split evenly, whatever each player put in
+        let pot: Uint128 = contributions.iter().map(|(_, amount)| amount).sum();
+        let share = pot.mul_floor(config.multiplier) / Uint128::from(contributions.len() as u128);
+
+        contributions
+            .into_iter()
+            .map(|(player, contribution)| (player.clone(), config.endowment - contribution + share))
+            .collect()
+    }
+
+    /// Applies a punishment round to the payoffs of the contribution round before it. Punishers
+    /// pay for the points they spend and their targets lose `impact` per `cost` points. Players
+    /// cannot spend more than their payoff from the contribution round, punishments over that
+    /// budget, of players outside the round or of oneself are ignored.
+    fn apply_punishments(payoffs: &mut [(Addr, Uint128)], round: &GameRound, punishment: &Punishment) {
+        let budgets: HashMap<Addr, Uint128> = payoffs.iter().cloned().collect();
+        let mut spent: HashMap<Addr, Uint128> = HashMap::new();
+        let mut received: HashMap<Addr, Uint128> = HashMap::new();
+
+        for (punisher, value) in round.reveals.iter() {
+            let budget = budgets.get(punisher).copied().unwrap_or_default();
+            let targets: Vec<(&Addr, Uint128)> = Self::punishments(value)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(target, points)| {
+                    budgets
+                        .get_key_value(&Addr::unchecked(target))
+                        .filter(|(target, _)| *target != punisher)
+                        .map(|(target, _)| (target, points))
+                })
+                .collect();
+            let total: Uint128 = targets.iter().map(|(_, points)| points).sum();
+            if total > budget {
+                continue;
+            }
+
+            spent.insert(punisher.clone(), total);
+            for (target, points) in targets {
+                *received.entry(target.clone()).or_default() +=
+                    points.multiply_ratio(punishment.impact, punishment.cost);
+            }
+        }
+
+        for (player, payoff) in payoffs.iter_mut() {
+            let cost = spent.get(player).copied().unwrap_or_default()
+                + received.get(player).copied().unwrap_or_default();
+            *payoff = payoff.saturating_sub(cost);
+        }
+    }
+}
+
+impl GameLifecycle for PublicGoods {
+    type Extension = PublicGoodsConfig;
+
+    fn is_valid_reveal_choice(value: &String) -> bool {
+        // players reveal the amount they contribute to the pot, or who they punish and by how much
+        Uint128::from_str(value).is_ok() || Self::punishments(value).is_some()
+    }
+
+    fn is_valid_move(
+        _deps: Deps,
+        game: &Game<PublicGoodsConfig>,
+        _player: &Addr,
+        value: &String,
+    ) -> StdResult<bool> {
+        // contributions cannot exceed the endowment and punishments are only revealed in
+        // punishment rounds
+        let config = &game.config.extension;
+        Ok(match Self::is_punishment_round(config, game.current_round) {
+            true => Self::punishments(value).is_some(),
+            false => Self::is_valid_contribution(value, config.endowment),
+        })
+    }
+
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<PublicGoodsConfig>,
+    ) -> Result<(), LifecycleError> {
+        if config.extension.endowment.is_zero() {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("endowment must be greater than zero"),
+            });
+        } else if config.extension.multiplier <= Decimal::one() {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("multiplier must be greater than one"),
+            });
+        }
+
+        if let Some(punishment) = &config.extension.punishment {
+            if punishment.cost.is_zero() || punishment.impact.is_zero() {
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("punishment cost and impact must be greater than zero"),
+                });
+            } else if config.max_rounds % 2 != 0 {
+                // every contribution round is followed by its punishment round
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("games with punishment must have an even number of rounds"),
+                });
+            } else if matches!(config.absence_policy, AbsencePolicy::DefaultMove { .. }) {
+                // a single default cannot be both a contribution and a punishment
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("games with punishment cannot have default moves"),
+                });
+            }
+        }
+        if let AbsencePolicy::DefaultMove { value } = &config.absence_policy {
+            if !Self::is_valid_contribution(value, config.extension.endowment) {
+                return Err(LifecycleError::InvalidDefaultMove {
+                    value: value.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn forfeit_reward(config: &GameConfig<PublicGoodsConfig>, round: u8) -> Uint128 {
+        // revealers of a voided contribution round keep their endowment, as if nobody had
+        // contributed, a voided punishment round leaves the round before it unchanged
+        match Self::is_punishment_round(&config.extension, round) {
+            true => Uint128::zero(),
+            false => config.extension.endowment,
+        }
+    }
+
+    fn calculate_rewards_and_winners(
+        _deps: Deps,
+        game: &mut Game<PublicGoodsConfig>,
+    ) -> Result<bool, LifecycleError> {
+        let config = game.config.extension.clone();
+        let mut winnings: HashMap<Addr, Uint128> = game
+            .players
+            .iter()
+            .map(|(player, _)| (player.clone(), Uint128::zero()))
+            .collect();
+
+        // with punishment, odd rounds collect contributions and the even round after each
+        // punishes, otherwise every round is a contribution round
+        let contribution_rounds = game
+            .scored_rounds()
+            .filter(|round| !Self::is_punishment_round(&config, round.id));
+        for round in contribution_rounds {
+            let mut payoffs = Self::contribution_payoffs(round, &config);
+
+            // a voided or forfeited punishment round leaves the contribution payoffs unchanged
+            let punishment_round = game.scored_rounds().find(|r| r.id == round.id + 1);
+            if let (Some(punishment), Some(punishment_round)) = (&config.punishment, punishment_round) {
+                Self::apply_punishments(&mut payoffs, punishment_round, punishment);
+            }
+
+            for (player, payoff) in payoffs {
+                *winnings.entry(player).or_default() += payoff;
+            }
+        }
+
+        game.scores = winnings;
+        Ok(true)
+    }
+}
@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{Addr, Decimal, Empty, Uint128};
+    use cw_game_lifecycle::lifecycle::GameLifecycle;
+    use cw_game_lifecycle::state::{Game, GameConfig};
+    use cw_game_lifecycle::test_utils::{
+        self, assert_lifecycle_error, balance_of, commit, create_game, execute_lifecycle, game_config,
+        play_round, reveal, start_game,
+    };
+    use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+
+    use crate::msg::StageResponse;
+    use crate::public_goods::{PublicGoods, PublicGoodsConfig, Punishment};
+
+    fn public_goods() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        ))
+    }
+
+    #[test]
+    fn public_goods_pot_is_multiplied_and_split_evenly() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+        start_game(&mut app, &game_contract, &[&p1, &p2, &p3], game_config(3, 2, PublicGoodsConfig::default()));
+
+        // round 1: a pot of 150 grows to 225 and each player receives 75
+        play_round(&mut app, &game_contract, &[(&p1, "100"), (&p2, "50"), (&p3, "0")]);
+        // round 2: everyone contributes their whole endowment, so the pot of 300 grows to 450
+        play_round(&mut app, &game_contract, &[(&p1, "100"), (&p2, "100"), (&p3, "100")]);
+
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        // payoffs are the endowment minus the contribution plus the share
+        let expected = [(&p1, 75 + 150), (&p2, 125 + 150), (&p3, 175 + 150)];
+        for (player, payoff) in expected {
+            assert_eq!(balance_of(&app, &p2e_contract, player), Uint128::new(payoff));
+        }
+    }
+
+    #[test]
+    fn public_goods_forfeited_round_pays_the_endowment() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+        let extension = PublicGoodsConfig { endowment: Uint128::new(40), ..Default::default() };
+        start_game(&mut app, &game_contract, &[&p1, &p2, &p3], game_config(3, 1, extension));
+
+        // player 3 does not reveal, which voids the round
+        for player in [&p1, &p2, &p3] {
+            commit(&mut app, &game_contract, player, "40").unwrap();
+        }
+        reveal(&mut app, &game_contract, &p1, "40").unwrap();
+        reveal(&mut app, &game_contract, &p2, "40").unwrap();
+        app.update_block(|block| block.height += 100);
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        // the revealers keep their endowment, as if nobody had contributed
+        assert_eq!(balance_of(&app, &p2e_contract, &p1), Uint128::new(40));
+        assert_eq!(balance_of(&app, &p2e_contract, &p2), Uint128::new(40));
+        assert_eq!(balance_of(&app, &p2e_contract, &p3), Uint128::zero());
+    }
+
+    #[test]
+    fn public_goods_rejects_invalid_config_and_contributions() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (_, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+
+        // a multiplier of one leaves nothing to gain from contributing
+        let config = GameConfig {
+            extension: PublicGoodsConfig {
+                multiplier: Decimal::one(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = create_game(&mut app, &game_contract, &p1, config).unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "multiplier must be greater than one".to_string(),
+            },
+        );
+
+        // contributions are amounts up to the endowment, punishments cannot be revealed in a
+        // game without a punishment stage
+        let reveals = [(&p1, "everything"), (&p2, "101"), (&p3, "")];
+        start_game(&mut app, &game_contract, &[&p1, &p2, &p3], game_config(3, 1, PublicGoodsConfig::default()));
+        for (player, contribution) in reveals {
+            commit(&mut app, &game_contract, player, contribution).unwrap();
+        }
+        for (player, contribution) in reveals {
+            let err = reveal(&mut app, &game_contract, player, contribution).unwrap_err();
+            assert_lifecycle_error(
+                err,
+                cw_game_lifecycle::ContractError::InvalidRevealChoice { game_id: 0, round: 1 },
+            );
+        }
+    }
+
+    #[test]
+    fn public_goods_reveals_must_match_the_round_phase() {
+        let deps = mock_dependencies();
+        let player = deps.api.addr_make("player_1");
+        let config = GameConfig {
+            max_rounds: 2,
+            extension: PublicGoodsConfig {
+                punishment: Some(Punishment { cost: Uint128::new(1), impact: Uint128::new(3) }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut game = Game::new(0, config, player.clone());
+        let punish = format!("{}:10", deps.api.addr_make("player_2"));
+
+        // odd rounds collect contributions of at most the endowment
+        game.current_round = 1;
+        for (value, valid) in [("100", true), ("101", false), (punish.as_str(), false), ("", false)] {
+            let is_valid = PublicGoods::is_valid_move(deps.as_ref(), &game, &player, &value.to_string());
+            assert_eq!(is_valid.unwrap(), valid, "{value} in a contribution round");
+        }
+
+        // even rounds punish the contribution round before them
+        game.current_round = 2;
+        for (value, valid) in [("100", false), (punish.as_str(), true), ("", true)] {
+            let is_valid = PublicGoods::is_valid_move(deps.as_ref(), &game, &player, &value.to_string());
+            assert_eq!(is_valid.unwrap(), valid, "{value} in a punishment round");
+        }
+    }
+
+    #[test]
+    fn public_goods_stage_counts_contribution_rounds() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (_, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+        let stage = |app: &App| -> StageResponse {
+            app.wrap()
+                .query_wasm_smart(game_contract.clone(), &crate::msg::QueryMsg::GetStage { game_id: 0 })
+                .unwrap()
+        };
+
+        // 4 lifecycle rounds make 2 contribution rounds, each followed by its punishment round
+        let extension = PublicGoodsConfig {
+            punishment: Some(Punishment { cost: Uint128::new(1), impact: Uint128::new(3) }),
+            ..Default::default()
+        };
+        start_game(&mut app, &game_contract, &[&p1, &p2, &p3], game_config(3, 4, extension));
+        assert_eq!(stage(&app), StageResponse { contribution_round: 1, contribution_rounds: 2, punishment: false });
+
+        play_round(&mut app, &game_contract, &[(&p1, "100"), (&p2, "50"), (&p3, "0")]);
+        assert_eq!(stage(&app), StageResponse { contribution_round: 1, contribution_rounds: 2, punishment: true });
+
+        play_round(&mut app, &game_contract, &[(&p1, ""), (&p2, ""), (&p3, "")]);
+        assert_eq!(stage(&app), StageResponse { contribution_round: 2, contribution_rounds: 2, punishment: false });
+    }
+
+    #[test]
+    fn public_goods_punishment_stage_reduces_payoffs() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+
+        // punishments need a punishment round after every contribution round
+        let punishment = Punishment { cost: Uint128::new(1), impact: Uint128::new(3) };
+        let config = GameConfig {
+            max_rounds: 3,
+            extension: PublicGoodsConfig {
+                punishment: Some(punishment.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = create_game(&mut app, &game_contract, &p1, config).unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "games with punishment must have an even number of rounds".to_string(),
+            },
+        );
+
+        let extension = PublicGoodsConfig {
+            punishment: Some(punishment),
+            ..Default::default()
+        };
+        start_game(&mut app, &game_contract, &[&p1, &p2, &p3], game_config(3, 2, extension));
+
+        // player 3 free rides: the pot of 200 grows to 300, so each player receives 100
+        play_round(&mut app, &game_contract, &[(&p1, "100"), (&p2, "100"), (&p3, "0")]);
+
+        // player 1 spends 10 to take 30 from player 3, player 2 overspends their payoff of 100
+        // and is ignored
+        let p1_punishment = format!("{}:10", p3);
+        let p2_punishment = format!("{}:10,{}:500", p3, p1);
+        play_round(
+            &mut app,
+            &game_contract,
+            &[(&p1, &p1_punishment), (&p2, &p2_punishment), (&p3, "")],
+        );
+
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        assert_eq!(balance_of(&app, &p2e_contract, &p1), Uint128::new(90));
+        assert_eq!(balance_of(&app, &p2e_contract, &p2), Uint128::new(100));
+        assert_eq!(balance_of(&app, &p2e_contract, &p3), Uint128::new(170));
+    }
+
+    #[test]
+    fn public_goods_slashed_bonds_are_split_without_leftovers() {
+        let mut app = App::default();
+        let owner = app.api().addr_make("owner");
+        let players: Vec<Addr> = (1..=4).map(|i| app.api().addr_make(&format!("player_{i}"))).collect();
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, public_goods(), test_utils::instantiate_msg);
+        for player in players.iter() {
+            let msg = cw_p2e::msg::ExecuteMsg::Transfer { recipient: player.to_string(), amount: Uint128::new(1_000) };
+            app.execute_contract(owner.clone(), p2e_contract.clone(), &msg, &[]).unwrap();
+            let msg = cw_p2e::msg::ExecuteMsg::IncreaseAllowance {
+                spender: game_contract.to_string(),
+                amount: Uint128::new(1_000),
+                expires: None,
+            };
+            app.execute_contract(player.clone(), p2e_contract.clone(), &msg, &[]).unwrap();
+        }
+
+        let config = GameConfig {
+            commit_bond: Some(Uint128::new(50)),
+            ..game_config(4, 2, PublicGoodsConfig::default())
+        };
+        start_game(&mut app, &game_contract, &players.iter().collect::<Vec<_>>(), config);
+        for player in players.iter() {
+            commit(&mut app, &game_contract, player, "0").unwrap();
+        }
+        for player in players.iter().take(3) {
+            reveal(&mut app, &game_contract, player, "0").unwrap();
+        }
+
+        // the bond of player 4 is split between the 3 players who revealed, the first of them
+        // also receiving the 2 left over from the split
+        app.update_block(|block| block.height += 100);
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &players[0], msg).unwrap();
+        let balances: Vec<u128> = players.iter().map(|p| balance_of(&app, &p2e_contract, p).u128()).collect();
+        assert_eq!(balances, vec![1_018, 1_016, 1_016, 950]);
+        assert_eq!(balance_of(&app, &p2e_contract, &game_contract), Uint128::zero());
+
+        let game: Game<PublicGoodsConfig> = app
+            .wrap()
+            .query_wasm_smart(game_contract, &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.total_escrow, Uint128::zero());
+    }
+}
@@ -1,36 +1,93 @@
 use std::collections::HashMap;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_game_lifecycle::{lifecycle::GameLifecycle, state::Game, error::ContractError as LifecycleError};
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use cw_game_lifecycle::{
+    error::ContractError as LifecycleError,
+    lifecycle::GameLifecycle,
+    state::{AbsencePolicy, Game, GameConfig, GameRound, GameRoundStatus},
+};
 
-use crate::ContractError;
+#[cw_serde]
+pub struct RockPaperScissors;
 
+/// Rock paper scissors specific parameters of a game
 #[cw_serde]
-pub struct RockPaperScissors {
-    pub choices: Vec<RockPaperScissorsChoices>,
+#[derive(Default)]
+pub struct RockPaperScissorsConfig {
+    pub variant: RockPaperScissorsVariant,
 }
 
-impl RockPaperScissors {
-    // returns 0 if p1 wins, 1 if p2 wins, 2 if draw
-    pub fn determine_winner(p1_choice: &RockPaperScissorsChoices, p2_choice: &RockPaperScissorsChoices) -> RockPaperScissorsWinner {
-        match (p1_choice, p2_choice) {
-            (RockPaperScissorsChoices::Rock, RockPaperScissorsChoices::Scissors) => RockPaperScissorsWinner::Player1,
-            (RockPaperScissorsChoices::Paper, RockPaperScissorsChoices::Rock) => RockPaperScissorsWinner::Player1,
-            (RockPaperScissorsChoices::Scissors, RockPaperScissorsChoices::Paper) => RockPaperScissorsWinner::Player1,
-            (RockPaperScissorsChoices::Scissors, RockPaperScissorsChoices::Rock) => RockPaperScissorsWinner::Player2,
-            (RockPaperScissorsChoices::Rock, RockPaperScissorsChoices::Paper) => RockPaperScissorsWinner::Player2,
-            (RockPaperScissorsChoices::Paper, RockPaperScissorsChoices::Scissors) => RockPaperScissorsWinner::Player2,
-            _ => RockPaperScissorsWinner::Draw,
+#[cw_serde]
+#[derive(Default)]
+pub enum RockPaperScissorsVariant {
+    #[default]
+    Classic,
+    LizardSpock,
+    // moves named by the game's creator, ordered in a cycle like the built-in variants
+    Custom { choices: Vec<String> },
+}
+
+impl RockPaperScissorsVariant {
+    /// Choices of the variant ordered in a cycle where every choice beats the (n - 1) / 2 choices
+    /// after it and loses to the others, which requires an odd number of choices
+    pub fn choices(&self) -> Vec<String> {
+        let choices: &[&str] = match self {
+            RockPaperScissorsVariant::Classic => &["rock", "scissors", "paper"],
+            RockPaperScissorsVariant::LizardSpock => {
+                &["rock", "scissors", "lizard", "paper", "spock"]
+            }
+            RockPaperScissorsVariant::Custom { choices } => return choices.clone(),
+        };
+        choices.iter().map(|choice| choice.to_string()).collect()
+    }
+
+    /// Checks the choices make a cycle: an odd number of at least three distinct, non empty names
+    pub fn validate(&self) -> Result<(), String> {
+        let choices = self.choices();
+        if choices.len() < 3 || choices.len() % 2 == 0 {
+            return Err(String::from("variants must have an odd number of at least 3 choices"));
+        } else if choices.iter().any(|choice| choice.is_empty()) {
+            return Err(String::from("choices cannot be empty"));
+        } else if (1..choices.len()).any(|i| choices[..i].contains(&choices[i])) {
+            return Err(String::from("choices must be distinct"));
         }
+        Ok(())
     }
 }
 
-#[cw_serde]
-pub enum RockPaperScissorsChoices {
-    Rock,
-    Paper,
-    Scissors,
+impl RockPaperScissors {
+    pub fn determine_winner(
+        variant: &RockPaperScissorsVariant,
+        p1_choice: &str,
+        p2_choice: &str,
+    ) -> RockPaperScissorsWinner {
+        let cycle = variant.choices();
+        let position = |choice| cycle.iter().position(|c| c == choice);
+        let (Some(p1), Some(p2)) = (position(p1_choice), position(p2_choice)) else {
+            // choices outside the variant are rejected on reveal
+            return RockPaperScissorsWinner::Draw;
+        };
+
+        // how far player 2's choice comes after player 1's in the cycle
+        match (p2 + cycle.len() - p1) % cycle.len() {
+            0 => RockPaperScissorsWinner::Draw,
+            distance if distance <= (cycle.len() - 1) / 2 => RockPaperScissorsWinner::Player1,
+            _ => RockPaperScissorsWinner::Player2,
+        }
+    }
+
+    /// Player who won a round both players revealed, or `None` for a draw
+    fn round_winner(variant: &RockPaperScissorsVariant, round: &GameRound) -> Option<Addr> {
+        let [(p1, p1_choice), (p2, p2_choice)] = round.reveals.as_slice() else {
+            return None;
+        };
+        match RockPaperScissors::determine_winner(variant, p1_choice, p2_choice) {
+            RockPaperScissorsWinner::Player1 => Some(p1.clone()),
+            RockPaperScissorsWinner::Player2 => Some(p2.clone()),
+            RockPaperScissorsWinner::Draw => None,
+        }
+    }
 }
 
 #[cw_serde]
@@ -40,74 +97,75 @@ pub enum RockPaperScissorsWinner {
     Draw,
 }
 
-impl TryFrom<String> for RockPaperScissorsChoices {
-    type Error = ContractError;
+impl GameLifecycle for RockPaperScissors {
+    type Extension = RockPaperScissorsConfig;
+
+    fn is_valid_reveal_choice(value: &String) -> bool {
+        // choices are checked against the game's variant in `is_valid_move`
+        !value.is_empty()
+    }
+
+    fn is_valid_move(
+        _deps: Deps,
+        game: &Game<RockPaperScissorsConfig>,
+        _player: &Addr,
+        value: &String,
+    ) -> StdResult<bool> {
+        // only the choices of the game's variant can be played
+        Ok(game.config.extension.variant.choices().contains(value))
+    }
 
-    fn try_from(choice: String) -> Result<Self, Self::Error> {
-        match choice.as_str() {
-            "rock" => Ok(RockPaperScissorsChoices::Rock),
-            "paper" => Ok(RockPaperScissorsChoices::Paper),
-            "scissors" => Ok(RockPaperScissorsChoices::Scissors),
-            _ => Err(ContractError::InvalidChoice {}),
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<RockPaperScissorsConfig>,
+    ) -> Result<(), LifecycleError> {
+        config
+            .extension
+            .variant
+            .validate()
+            .map_err(|reason| LifecycleError::InvalidGameConfig { reason })?;
+        if let AbsencePolicy::DefaultMove { value } = &config.absence_policy {
+            if !config.extension.variant.choices().contains(value) {
+                return Err(LifecycleError::InvalidDefaultMove {
+                    value: value.clone(),
+                });
+            }
         }
+        Ok(())
     }
-}
 
-impl GameLifecycle for RockPaperScissors {
-    fn is_valid_reveal_choice(value: &String) -> bool {
-        let choice = RockPaperScissorsChoices::try_from(value.clone());
-        choice.is_ok()
+    fn is_decided(game: &Game<RockPaperScissorsConfig>) -> bool {
+        // a best of N match is over once a player has won more than half of its rounds, including
+        // rounds won because the opponent forfeited them
+        let variant = &game.config.extension.variant;
+        let mut wins: HashMap<Addr, u8> = HashMap::new();
+        for round in game.scored_rounds() {
+            if let Some(winner) = RockPaperScissors::round_winner(variant, round) {
+                *wins.entry(winner).or_default() += 1;
+            }
+        }
+        game.rounds
+            .iter()
+            .filter(|r| r.status == GameRoundStatus::Ended && !r.forfeited_by.is_empty())
+            .flat_map(|r| r.reveals.iter())
+            .for_each(|(player, _)| *wins.entry(player.clone()).or_default() += 1);
+
+        wins.values().any(|wins| *wins as u16 * 2 > game.config.max_rounds as u16)
     }
 
     fn calculate_rewards_and_winners(
-        game: &mut Game,
+        _deps: Deps,
+        game: &mut Game<RockPaperScissorsConfig>,
     ) -> Result<bool, LifecycleError> {
+        let variant = game.config.extension.variant.clone();
         let mut winnings: HashMap<Addr, Uint128> = HashMap::from([
             (game.players[0].0.clone(), Uint128::zero()),
             (game.players[1].0.clone(), Uint128::zero()),
         ]);
 
-        game.rounds.iter().for_each(|round| {
-            let reveals = round.reveals.as_slice();
-            
-            match (reveals.get(0), reveals.get(1)) {
-                (Some(p1_reveal), Some(p2_reveal)) => {
-                    // it's safe to unwrap because we've already checked the choice is valid in the reveal step
-                    let p1_choice = RockPaperScissorsChoices::try_from(p1_reveal.1.clone()).unwrap();
-                    let p2_choice = RockPaperScissorsChoices::try_from(p2_reveal.1.clone()).unwrap();
-                    let winner = RockPaperScissors::determine_winner(&p1_choice, &p2_choice);
-
-                    match winner {
-                        RockPaperScissorsWinner::Player1 => {
-                            winnings.insert(
-                                p1_reveal.0.clone(),
-                                winnings.get(&p1_reveal.0).unwrap() + Uint128::from(100u128),
-                            );
-                        }
-                        RockPaperScissorsWinner::Player2 => {
-                            winnings.insert(
-                                p2_reveal.0.clone(),
-                                winnings.get(&p2_reveal.0).unwrap() + Uint128::from(100u128),
-                            );
-                        }
-                        RockPaperScissorsWinner::Draw => {}
-                    }
-                }
-                // p1 revealed, p2 did not reveal, p1 gets 100
-                (Some(p1_reveal), None) => {
-                    winnings.insert(
-                        p1_reveal.0.clone(),
-                        winnings.get(&p1_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                // p2 revealed, p1 did not reveal, p2 gets 100
-                (None, Some(p2_reveal)) => {
-                    winnings.insert(
-                        p2_reveal.0.clone(),
-                        winnings.get(&p2_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                _ => {}
+        game.scored_rounds().for_each(|round| {
+            if let Some(winner) = RockPaperScissors::round_winner(&variant, round) {
+                *winnings.entry(winner).or_default() += Uint128::from(100u128);
             }
         });
 
@@ -115,4 +173,3 @@ impl GameLifecycle for RockPaperScissors {
         Ok(true)
     }
 }
-
@@ -1,11 +1,14 @@
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coins, Addr, Uint128};
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
     use cw_multi_test::{error::AnyResult, App, ContractWrapper, Executor};
     use sha2::{Sha256, Digest};
     use hex;
 
-    use crate::rock_paper_scissors::{RockPaperScissors, RockPaperScissorsChoices, RockPaperScissorsWinner};
+    use crate::rock_paper_scissors::{
+        RockPaperScissors, RockPaperScissorsConfig, RockPaperScissorsVariant,
+        RockPaperScissorsWinner,
+    };
 
     ///
     /// A wrapper around the code ID of the P2E token contract
@@ -116,6 +119,9 @@ mod tests {
                 base_url: "https://example.com".to_string(),
                 image_url: "https://example.com/image.png".to_string(),
                 token_contract: p2e_contract.addr(),
+                rating_config: None,
+                identity_contract: None,
+                attestation_pubkey: None,
             };
             app.instantiate_contract(code_id.0, sender, &init_msg, &[], label, None)
                 .map(Self::from)
@@ -178,6 +184,36 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
+            });
+            app.execute_contract(p.clone(), game_contract.addr(), &msg, &[]).unwrap();
+        });
+    }
+
+    fn hash_choice(choice: &str, nonce: u64) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(choice.as_bytes());
+        hasher.update(nonce.to_be_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    fn play_round(app: &mut App, game_contract: &RockPaperScissorsContract, moves: Vec<(Addr, &str)>) {
+        let nonce = 123u64;
+
+        // every player commits first, then every player reveals
+        moves.iter().for_each(|(p, choice)| {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_choice(choice, nonce),
+                amount: None,
+            });
+            app.execute_contract(p.clone(), game_contract.addr(), &msg, &[]).unwrap();
+        });
+        moves.iter().for_each(|(p, choice)| {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: choice.to_string(),
+                nonce,
             });
             app.execute_contract(p.clone(), game_contract.addr(), &msg, &[]).unwrap();
         });
@@ -219,7 +255,7 @@ mod tests {
 
         assert_eq!(counter, 1);
 
-        let game: cw_game_lifecycle::state::Game = app
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
             .wrap()
             .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
             .unwrap();
@@ -261,6 +297,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
@@ -270,6 +307,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
@@ -319,6 +357,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
@@ -328,6 +367,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
@@ -369,6 +409,7 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
             });
             app.execute_contract(p.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
         });
@@ -377,6 +418,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: p3.to_string(),
+            attestation: None,
         });
         let res = app.execute_contract(p3.clone(), rock_paper_scissors_contract.addr(), &msg, &[]);
         assert!(res.is_err());
@@ -486,28 +528,1396 @@ mod tests {
 
     #[test]
     fn rock_paper_scissors_player_choice_winner_calculation() {
-        let choice_a = RockPaperScissorsChoices::Rock;
-        let choice_b = RockPaperScissorsChoices::Paper;
-        
-        let winner = RockPaperScissors::determine_winner(&choice_a, &choice_b);
+        let winner = RockPaperScissors::determine_winner(&RockPaperScissorsVariant::Classic, "rock", "paper");
         assert_eq!(winner, RockPaperScissorsWinner::Player2);
 
-        let choice_a = RockPaperScissorsChoices::Rock;
-        let choice_b = RockPaperScissorsChoices::Scissors;
-        
-        let winner = RockPaperScissors::determine_winner(&choice_a, &choice_b);
+        let winner = RockPaperScissors::determine_winner(&RockPaperScissorsVariant::Classic, "rock", "scissors");
         assert_eq!(winner, RockPaperScissorsWinner::Player1);
 
-        let choice_a = RockPaperScissorsChoices::Paper;
-        let choice_b = RockPaperScissorsChoices::Rock;
-        
-        let winner = RockPaperScissors::determine_winner(&choice_a, &choice_b);
+        let winner = RockPaperScissors::determine_winner(&RockPaperScissorsVariant::Classic, "paper", "rock");
         assert_eq!(winner, RockPaperScissorsWinner::Player1);
 
-        let choice_a = RockPaperScissorsChoices::Rock;
-        let choice_b = RockPaperScissorsChoices::Rock;
-        
-        let winner = RockPaperScissors::determine_winner(&choice_a, &choice_b);
+        let winner = RockPaperScissors::determine_winner(&RockPaperScissorsVariant::Classic, "rock", "rock");
         assert_eq!(winner, RockPaperScissorsWinner::Draw);
     }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_winner_calculation() {
+        // every choice beats two others and loses to the remaining two
+        for (choice_a, beaten) in [
+            ("rock", ["scissors", "lizard"]),
+            ("paper", ["rock", "spock"]),
+            ("scissors", ["paper", "lizard"]),
+            ("lizard", ["paper", "spock"]),
+            ("spock", ["rock", "scissors"]),
+        ] {
+            for choice_b in RockPaperScissorsVariant::LizardSpock.choices() {
+                let winner = RockPaperScissors::determine_winner(
+                    &RockPaperScissorsVariant::LizardSpock,
+                    choice_a,
+                    &choice_b,
+                );
+                let expected = if choice_a == choice_b {
+                    RockPaperScissorsWinner::Draw
+                } else if beaten.contains(&choice_b.as_str()) {
+                    RockPaperScissorsWinner::Player1
+                } else {
+                    RockPaperScissorsWinner::Player2
+                };
+                assert_eq!(winner, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_game_flow() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        // the variant is chosen when the game is created, default moves can use its choices
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 2,
+            absence_policy: cw_game_lifecycle::state::AbsencePolicy::DefaultMove {
+                value: "spock".to_string(),
+            },
+            extension: RockPaperScissorsConfig { variant: RockPaperScissorsVariant::LizardSpock },
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let choices: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetChoices { game_id: 0 },
+            )
+            .unwrap();
+        assert_eq!(choices, RockPaperScissorsVariant::LizardSpock.choices());
+
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // spock vaporizes rock, then lizard poisons spock
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "spock"), (p2.clone(), "rock")]);
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "spock"), (p2.clone(), "lizard")]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        for player in [&p1, &p2] {
+            let balance: cw20::BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: player.to_string() })
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(100));
+        }
+
+        // classic games keep the three original choices
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config: cw_game_lifecycle::state::GameConfig::default(),
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let choices: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetChoices { game_id: 1 },
+            )
+            .unwrap();
+        assert_eq!(choices, vec!["rock", "scissors", "paper"]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_custom_variant() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let create_game = |app: &mut App, choices: &[&str]| {
+            let variant = RockPaperScissorsVariant::Custom {
+                choices: choices.iter().map(|choice| choice.to_string()).collect(),
+            };
+            let msg =
+                crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                    config: cw_game_lifecycle::state::GameConfig {
+                        max_players: Some(2),
+                        max_rounds: 1,
+                        extension: RockPaperScissorsConfig { variant },
+                        ..Default::default()
+                    },
+                });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
+        };
+
+        // the choices must make a cycle where every choice beats as many choices as it loses to
+        for (choices, reason) in [
+            (vec!["fire", "water"], "variants must have an odd number of at least 3 choices"),
+            (vec!["fire", "sponge", "paper", "water"], "variants must have an odd number of at least 3 choices"),
+            (vec!["fire", "", "water"], "choices cannot be empty"),
+            (vec!["fire", "water", "fire"], "choices must be distinct"),
+        ] {
+            let err = create_game(&mut app, &choices).unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                    reason: reason.to_string(),
+                })
+                .to_string()
+            );
+        }
+
+        // fire burns sponge, sponge soaks up water and water puts out fire
+        create_game(&mut app, &["fire", "sponge", "water"]).unwrap();
+        let choices: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetChoices { game_id: 0 },
+            )
+            .unwrap();
+        assert_eq!(choices, vec!["fire", "sponge", "water"]);
+
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "sponge"), (p2.clone(), "water")]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: p1.to_string() })
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(100));
+    }
+
+    #[test]
+    fn rock_paper_scissors_best_of_five_ends_when_decided() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 5,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let status = |app: &App| {
+            let status: cw_game_lifecycle::state::GameStatus = app
+                .wrap()
+                .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGameStatus { game_id: 0 })
+                .unwrap();
+            status
+        };
+
+        // draws do not count towards the three wins needed
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "rock"), (p2.clone(), "rock")]);
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "rock"), (p2.clone(), "scissors")]);
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "paper"), (p2.clone(), "rock")]);
+        assert_eq!(status(&app), cw_game_lifecycle::state::GameStatus::InProgress);
+
+        // player 1 wins a third round, so the last round is never played
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "scissors"), (p2.clone(), "paper")]);
+        assert_eq!(status(&app), cw_game_lifecycle::state::GameStatus::RoundsFinished);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: p1.to_string() })
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(300));
+    }
+
+    #[test]
+    fn rock_paper_scissors_player_stats() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        // create a single round game
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // player 2 wins the only round
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "rock"), (p2.clone(), "paper")]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let block_height = app.block_info().height;
+        let stats: cw_game_lifecycle::state::PlayerStats = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetPlayerStats { player: p2.clone() })
+            .unwrap();
+        assert_eq!(stats, cw_game_lifecycle::state::PlayerStats {
+            games_played: 1,
+            wins: 1,
+            losses: 0,
+            draws: 0,
+            total_rewards: Uint128::new(100),
+            current_win_streak: 1,
+            best_win_streak: 1,
+            last_played_block: block_height,
+        });
+
+        // stats can also be looked up by the telegram ID used to join the game
+        let stats: cw_game_lifecycle::state::PlayerStats = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetPlayerStatsByTelegramId { telegram_id: p1.to_string() },
+            )
+            .unwrap();
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.losses, 1);
+        assert_eq!(stats.current_win_streak, 0);
+        assert_eq!(stats.total_rewards, Uint128::zero());
+
+        // players who never played have empty stats
+        let p3 = app.api().addr_make("player_3");
+        let stats: cw_game_lifecycle::state::PlayerStats = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetPlayerStats { player: p3 })
+            .unwrap();
+        assert_eq!(stats, cw_game_lifecycle::state::PlayerStats::default());
+    }
+
+    #[test]
+    fn rock_paper_scissors_elo_ratings() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // unrated players start at the initial rating
+        let rating: cw_game_lifecycle::msg::RatingResponse = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetRating { player: p1.clone() })
+            .unwrap();
+        assert_eq!(rating.rating, Decimal::from_ratio(1200u64, 1u64));
+        assert!(rating.provisional);
+
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "scissors"), (p2.clone(), "rock")]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // evenly rated players move by half the provisional K-factor (40)
+        let top_ratings: Vec<cw_game_lifecycle::msg::RatingResponse> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetTopRatings { start_after: None, limit: None },
+            )
+            .unwrap();
+        assert_eq!(top_ratings.len(), 2);
+        assert_eq!(top_ratings[0].player, p2);
+        assert_eq!(top_ratings[0].rating, Decimal::from_ratio(1220u64, 1u64));
+        assert_eq!(top_ratings[0].games_rated, 1);
+        assert_eq!(top_ratings[1].player, p1);
+        assert_eq!(top_ratings[1].rating, Decimal::from_ratio(1180u64, 1u64));
+
+        // the next page starts after the last returned player
+        let top_ratings: Vec<cw_game_lifecycle::msg::RatingResponse> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetTopRatings { start_after: Some(p2.clone()), limit: Some(1) },
+            )
+            .unwrap();
+        assert_eq!(top_ratings.len(), 1);
+        assert_eq!(top_ratings[0].player, p1);
+    }
+
+    #[test]
+    fn rock_paper_scissors_ended_games_cannot_be_ended_again() {
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "rock"), (p2.clone(), "paper")]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // not even an admin can settle the game a second time
+        let err = app
+            .execute_contract(owner, rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::CannotCloseGame {
+                reason: "Game already ended!".to_string(),
+            })
+            .to_string()
+        );
+
+        // rewards, stats and ratings are only counted once
+        let balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: p2.to_string() })
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(100));
+        let stats: cw_game_lifecycle::state::PlayerStats = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetPlayerStats { player: p2.clone() })
+            .unwrap();
+        assert_eq!(stats.games_played, 1);
+        let rating: cw_game_lifecycle::msg::RatingResponse = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetRating { player: p2.clone() })
+            .unwrap();
+        assert_eq!(rating.games_rated, 1);
+    }
+
+    #[test]
+    fn rock_paper_scissors_unplayed_games_do_not_count() {
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        // the admin ends the game before it starts
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(owner, rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // no round was played, so neither stats nor ratings change
+        for player in [&p1, &p2] {
+            let stats: cw_game_lifecycle::state::PlayerStats = app
+                .wrap()
+                .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetPlayerStats { player: player.clone() })
+                .unwrap();
+            assert_eq!(stats, cw_game_lifecycle::state::PlayerStats::default());
+            let rating: cw_game_lifecycle::msg::RatingResponse = app
+                .wrap()
+                .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetRating { player: player.clone() })
+                .unwrap();
+            assert_eq!(rating.rating, Decimal::from_ratio(1200u64, 1u64));
+            assert_eq!(rating.games_rated, 0);
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_join_game_with_identity_registry() {
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+
+        // the registry binds player 1 to their telegram ID, player 2 is not registered
+        let identity_code_id = app.store_code(Box::new(ContractWrapper::new(
+            cw_identity::contract::execute,
+            cw_identity::contract::instantiate,
+            cw_identity::contract::query,
+        )));
+        let identity_contract = app
+            .instantiate_contract(
+                identity_code_id,
+                owner.clone(),
+                &cw_identity::msg::InstantiateMsg { admins: vec![] },
+                &[],
+                "identity",
+                None,
+            )
+            .unwrap();
+        let msg = cw_identity::msg::ExecuteMsg::Bind {
+            telegram_id: "1234567890".to_string(),
+            address: p1.clone(),
+        };
+        app.execute_contract(owner.clone(), identity_contract.clone(), &msg, &[]).unwrap();
+
+        let p2e_contract = P2ETokenCodeId::store_code(&mut app)
+            .instantiate(&mut app, owner.clone(), "test", None)
+            .unwrap();
+        let code_id = RockPaperScissorsCodeId::store_code(&mut app);
+        let init_msg = crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract: p2e_contract.addr(),
+            rating_config: None,
+            identity_contract: Some(identity_contract),
+            attestation_pubkey: None,
+        };
+        let rock_paper_scissors_contract = app
+            .instantiate_contract(code_id.0, owner.clone(), &init_msg, &[], "test", None)
+            .unwrap();
+
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config: cw_game_lifecycle::state::GameConfig::default(),
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[]).unwrap();
+
+        // the telegram ID claimed by player 1 is replaced by the registered one
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+            game_id: 0,
+            telegram_id: "someone_else".to_string(),
+            attestation: None,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[]).unwrap();
+
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.clone(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.players, vec![(p1.clone(), "1234567890".to_string())]);
+
+        // unregistered players cannot join
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+            game_id: 0,
+            telegram_id: "0987654321".to_string(),
+            attestation: None,
+        });
+        let err = app
+            .execute_contract(p2.clone(), rock_paper_scissors_contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(
+                cw_game_lifecycle::ContractError::TelegramIdNotRegistered { player: p2 }
+            ).to_string()
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_join_game_with_attestation() {
+        use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+
+        // fixed bot keypair used to sign telegram ID attestations
+        let bot_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let bot_pubkey = bot_key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+
+        let p2e_contract = P2ETokenCodeId::store_code(&mut app)
+            .instantiate(&mut app, owner.clone(), "test", None)
+            .unwrap();
+        let code_id = RockPaperScissorsCodeId::store_code(&mut app);
+        let init_msg = crate::msg::InstantiateMsg {
+            base_url: "https://example.com".to_string(),
+            image_url: "https://example.com/image.png".to_string(),
+            token_contract: p2e_contract.addr(),
+            rating_config: None,
+            identity_contract: None,
+            attestation_pubkey: Some(bot_pubkey.into()),
+        };
+        let rock_paper_scissors_contract = app
+            .instantiate_contract(code_id.0, owner.clone(), &init_msg, &[], "test", None)
+            .unwrap();
+
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config: cw_game_lifecycle::state::GameConfig::default(),
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[]).unwrap();
+
+        let expires_at = app.block_info().time.seconds() + 60;
+        let attest = |telegram_id: &str, expires_at: u64| {
+            let digest = cw_game_lifecycle::state::TelegramAttestation::digest(
+                telegram_id,
+                &p1,
+                &rock_paper_scissors_contract,
+                expires_at,
+            );
+            let signature: Signature = bot_key.sign_prehash(&digest).unwrap();
+            cw_game_lifecycle::state::TelegramAttestation {
+                expires_at,
+                signature: signature.to_bytes().to_vec().into(),
+            }
+        };
+        let join_msg = |telegram_id: &str, attestation: Option<cw_game_lifecycle::state::TelegramAttestation>| {
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id: 0,
+                telegram_id: telegram_id.to_string(),
+                attestation,
+            })
+        };
+        let lifecycle_error = |err: cw_game_lifecycle::ContractError| {
+            crate::ContractError::GameLifecycle(err).to_string()
+        };
+
+        // joining without an attestation is rejected
+        let err = app
+            .execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &join_msg("1234567890", None), &[])
+            .unwrap_err();
+        assert_eq!(err.root_cause().to_string(), lifecycle_error(cw_game_lifecycle::ContractError::AttestationRequired {}));
+
+        // an attestation for another telegram ID does not verify
+        let msg = join_msg("1234567890", Some(attest("0987654321", expires_at)));
+        let err = app
+            .execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(err.root_cause().to_string(), lifecycle_error(cw_game_lifecycle::ContractError::InvalidAttestation {}));
+
+        // expired attestations are rejected
+        let expired = app.block_info().time.seconds();
+        let msg = join_msg("1234567890", Some(attest("1234567890", expired)));
+        let err = app
+            .execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(err.root_cause().to_string(), lifecycle_error(cw_game_lifecycle::ContractError::AttestationExpired {}));
+
+        // a valid attestation lets the player join
+        let msg = join_msg("1234567890", Some(attest("1234567890", expires_at)));
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.clone(), &msg, &[]).unwrap();
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.clone(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.players, vec![(p1.clone(), "1234567890".to_string())]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_delegated_commit_and_reveal() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let bot = app.api().addr_make("bot");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 2,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // player 1 lets the bot make two moves for them in this game only
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::GrantDelegate {
+            delegate: bot.clone(),
+            game_id: Some(0),
+            expires_at: cw_utils::Expiration::Never {},
+            max_actions: 2,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // the bot cannot act for player 2, who never granted it anything
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRoundAsDelegate {
+            game_id: 0,
+            value: hash_choice("rock", 123),
+            amount: None,
+            player: p2.clone(),
+        });
+        let err = app
+            .execute_contract(bot.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::error::ContractError::Unauthorized {}).to_string()
+        );
+
+        // the bot commits and reveals for player 1
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRoundAsDelegate {
+            game_id: 0,
+            value: hash_choice("rock", 123),
+            amount: None,
+            player: p1.clone(),
+        });
+        app.execute_contract(bot.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+            game_id: 0,
+            value: hash_choice("scissors", 123),
+            amount: None,
+        });
+        app.execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRoundAsDelegate {
+            game_id: 0,
+            value: "rock".to_string(),
+            nonce: 123,
+            player: p1.clone(),
+        });
+        app.execute_contract(bot.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let delegation: Option<cw_game_lifecycle::state::Delegation> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetDelegation { player: p1.clone(), delegate: bot.clone() },
+            )
+            .unwrap();
+        assert_eq!(delegation.unwrap().actions_used, 2);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+            game_id: 0,
+            value: "scissors".to_string(),
+            nonce: 123,
+        });
+        app.execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // the grant is used up
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRoundAsDelegate {
+            game_id: 0,
+            value: hash_choice("rock", 123),
+            amount: None,
+            player: p1.clone(),
+        });
+        let err = app
+            .execute_contract(bot.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::error::ContractError::DelegationExhausted {
+                player: p1.clone(),
+                delegate: bot.clone(),
+            })
+            .to_string()
+        );
+
+        // after revoking, the delegation is gone
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevokeDelegate {
+            delegate: bot.clone(),
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let delegation: Option<cw_game_lifecycle::state::Delegation> = app
+            .wrap()
+            .query_wasm_smart(
+                rock_paper_scissors_contract.addr(),
+                &crate::msg::QueryMsg::GetDelegation { player: p1.clone(), delegate: bot.clone() },
+            )
+            .unwrap();
+        assert_eq!(delegation, None);
+    }
+
+    #[test]
+    fn rock_paper_scissors_relay_signed_moves() {
+        use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+        use cw_game_lifecycle::msg::{RelayAction, RelayPayload};
+
+        let mut app = mock_app();
+        let p2 = app.api().addr_make("player_2");
+        let relayer = app.api().addr_make("bot");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        // player 1 is identified by their secp256k1 key
+        let p1_key = SigningKey::from_slice(&[9u8; 32]).unwrap();
+        let p1_pubkey = p1_key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+        let p1 = cw_game_lifecycle::helpers::pubkey_to_address(app.api(), &p1_pubkey).unwrap();
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+
+        let relay_msg = |key: &SigningKey, action: RelayAction, nonce: u64| {
+            let payload = RelayPayload {
+                contract: rock_paper_scissors_contract.addr(),
+                game_id: 0,
+                round: game.current_round,
+                action,
+                nonce,
+            };
+            let signature: Signature = key.sign_prehash(&payload.digest().unwrap()).unwrap();
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RelaySigned {
+                payload,
+                pubkey: p1_pubkey.clone().into(),
+                signature: signature.to_bytes().to_vec().into(),
+            })
+        };
+        let lifecycle_error = |err: cw_game_lifecycle::ContractError| {
+            crate::ContractError::GameLifecycle(err).to_string()
+        };
+        let commit = RelayAction::Commit { value: hash_choice("paper", 123), amount: None };
+
+        // a payload signed by another key is rejected
+        let other_key = SigningKey::from_slice(&[8u8; 32]).unwrap();
+        let err = app
+            .execute_contract(relayer.clone(), rock_paper_scissors_contract.addr(), &relay_msg(&other_key, commit.clone(), 0), &[])
+            .unwrap_err();
+        assert_eq!(err.root_cause().to_string(), lifecycle_error(cw_game_lifecycle::ContractError::InvalidRelaySignature {}));
+
+        // the relayer submits player 1's commit, which cannot be replayed
+        let msg = relay_msg(&p1_key, commit, 0);
+        app.execute_contract(relayer.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        let err = app
+            .execute_contract(relayer.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            lifecycle_error(cw_game_lifecycle::ContractError::InvalidRelayNonce { expected: 1 })
+        );
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+            game_id: 0,
+            value: hash_choice("rock", 123),
+            amount: None,
+        });
+        app.execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let msg = relay_msg(&p1_key, RelayAction::Reveal { value: "paper".to_string(), nonce: 123 }, 1);
+        app.execute_contract(relayer.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let nonce: u64 = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetRelayNonce { player: p1.clone() })
+            .unwrap();
+        assert_eq!(nonce, 2);
+
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.rounds[0].reveals, vec![(p1.clone(), "paper".to_string())]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_round_expires_after_seconds() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        // players get 60 seconds to choose
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 1,
+            commit_duration: Some(cw_utils::Duration::Time(60)),
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let started_at = app.block_info().time;
+        let current_round: cw_game_lifecycle::msg::CurrentRoundResponse = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetCurrentRound { game_id: 0 })
+            .unwrap();
+        assert_eq!(current_round, cw_game_lifecycle::msg::CurrentRoundResponse {
+            round: 1,
+            status: Some(cw_game_lifecycle::state::GameRoundStatus::Pending),
+            expires_at: Some(cw_utils::Expiration::AtTime(started_at.plus_seconds(60))),
+            expires_at_time: Some(started_at.plus_seconds(60)),
+            now: started_at,
+        });
+
+        // a single block with a long block time is enough for the round to expire
+        app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(61);
+        });
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+            game_id: 0,
+            value: hash_choice("rock", 123),
+            amount: None,
+        });
+        let err = app
+            .execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::RoundExpired { game_id: 0, round: 1 }).to_string()
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_separate_commit_and_reveal_windows() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let p3 = app.api().addr_make("player_3");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(3),
+            min_players: 3,
+            max_rounds: 1,
+            commit_duration: Some(cw_utils::Duration::Height(10)),
+            reveal_duration: Some(cw_utils::Duration::Height(5)),
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone(), p3.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let commit = |choice: &str| {
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_choice(choice, 123),
+                amount: None,
+            })
+        };
+        let reveal = |choice: &str| {
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: choice.to_string(),
+                nonce: 123,
+            })
+        };
+        let advance = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound {
+            game_id: 0,
+        });
+        let lifecycle_error = |err: cw_game_lifecycle::ContractError| {
+            crate::ContractError::GameLifecycle(err).to_string()
+        };
+
+        // player 1 commits right away, player 2 in the last block of the commit window
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &commit("rock"), &[]).unwrap();
+        app.update_block(|block| block.height += 9);
+        app.execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &commit("paper"), &[]).unwrap();
+
+        // reveals are not accepted and the round cannot be advanced while the commit window is open
+        let err = app
+            .execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &reveal("rock"), &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            lifecycle_error(cw_game_lifecycle::ContractError::RoundNotCommitted { game_id: 0, round: 1 })
+        );
+        let err = app
+            .execute_contract(p3.clone(), rock_paper_scissors_contract.addr(), &advance, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            lifecycle_error(cw_game_lifecycle::ContractError::RoundNotExpired { game_id: 0, round: 1 })
+        );
+
+        // player 3 never commits, once the commit window closes the reveal window opens
+        app.update_block(|block| block.height += 1);
+        let err = app
+            .execute_contract(p3.clone(), rock_paper_scissors_contract.addr(), &commit("scissors"), &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            lifecycle_error(cw_game_lifecycle::ContractError::RoundExpired { game_id: 0, round: 1 })
+        );
+        app.execute_contract(p3.clone(), rock_paper_scissors_contract.addr(), &advance, &[]).unwrap();
+
+        let reveal_opened_at = app.block_info().height;
+        let current_round: cw_game_lifecycle::msg::CurrentRoundResponse = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetCurrentRound { game_id: 0 })
+            .unwrap();
+        assert_eq!(current_round.status, Some(cw_game_lifecycle::state::GameRoundStatus::Committed));
+        assert_eq!(current_round.expires_at, Some(cw_utils::Expiration::AtHeight(reveal_opened_at + 5)));
+
+        // player 2 committed at the last block but still gets the full reveal window
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &reveal("rock"), &[]).unwrap();
+        app.update_block(|block| block.height += 4);
+        app.execute_contract(p2.clone(), rock_paper_scissors_contract.addr(), &reveal("paper"), &[]).unwrap();
+
+        // every committed player revealed, so the round closed and the game is finished
+        let status: cw_game_lifecycle::state::GameStatus = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGameStatus { game_id: 0 })
+            .unwrap();
+        assert_eq!(status, cw_game_lifecycle::state::GameStatus::RoundsFinished);
+    }
+
+    #[test]
+    fn rock_paper_scissors_absence_policies() {
+        // player 1 plays scissors and player 2 never shows up, returns the score of each player
+        fn scores(absence_policy: cw_game_lifecycle::state::AbsencePolicy) -> Vec<String> {
+            let mut app = mock_app();
+            let p1 = app.api().addr_make("player_1");
+            let p2 = app.api().addr_make("player_2");
+            let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+
+            let config = cw_game_lifecycle::state::GameConfig {
+                max_players: Some(2),
+                max_rounds: 1,
+                commit_duration: Some(cw_utils::Duration::Height(5)),
+                absence_policy,
+                ..Default::default()
+            };
+            let msg =
+                crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                    config,
+                });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+            join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+                game_id: 0,
+            });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_choice("scissors", 123),
+                amount: None,
+            });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+            // the commit window closes and player 1's reveal closes the round
+            app.update_block(|block| block.height += 5);
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id: 0,
+                value: "scissors".to_string(),
+                nonce: 123,
+            });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+                game_id: 0,
+            });
+            let res = app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+            [p1, p2]
+                .iter()
+                .map(|player| {
+                    res.events
+                        .iter()
+                        .find(|e| {
+                            e.ty == "wasm-game_winnings"
+                                && e.attributes.iter().any(|a| a.key == "player" && a.value == player.as_str())
+                        })
+                        .and_then(|e| e.attributes.iter().find(|a| a.key == "score"))
+                        .map(|a| a.value.clone())
+                        .unwrap_or_default()
+                })
+                .collect()
+        }
+
+        // player 1 wins the forfeited round
+        assert_eq!(scores(cw_game_lifecycle::state::AbsencePolicy::Forfeit), vec!["100", "0"]);
+        // player 2 is recorded as playing rock, which beats scissors
+        assert_eq!(
+            scores(cw_game_lifecycle::state::AbsencePolicy::DefaultMove { value: "rock".to_string() }),
+            vec!["0", "100"]
+        );
+        // nobody scores a voided round
+        assert_eq!(scores(cw_game_lifecycle::state::AbsencePolicy::Void), vec!["0", "0"]);
+
+        // default moves must be valid choices for the game
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let (_, rock_paper_scissors_contract) = setup_contracts(&mut app, None);
+        let config = cw_game_lifecycle::state::GameConfig {
+            absence_policy: cw_game_lifecycle::state::AbsencePolicy::DefaultMove { value: "lizard".to_string() },
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        let err = app
+            .execute_contract(p1, rock_paper_scissors_contract.addr(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidDefaultMove {
+                value: "lizard".to_string()
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_commit_bonds() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(
+            &mut app,
+            Some(vec![
+                cw20::Cw20Coin { address: p1.to_string(), amount: Uint128::new(1_000) },
+                cw20::Cw20Coin { address: p2.to_string(), amount: Uint128::new(1_000) },
+            ]),
+        );
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 2,
+            reveal_duration: Some(cw_utils::Duration::Height(5)),
+            commit_bond: Some(Uint128::new(50)),
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        let balance = |app: &App, player: &Addr| {
+            let balance: cw20::BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: player.to_string() })
+                .unwrap();
+            balance.balance.u128()
+        };
+
+        // both players reveal, so both bonds are returned
+        play_round(&mut app, &rock_paper_scissors_contract, vec![(p1.clone(), "rock"), (p2.clone(), "paper")]);
+        assert_eq!(balance(&app, &p1), 1_000);
+        assert_eq!(balance(&app, &p2), 1_000);
+
+        // both players commit, but player 2 sees they would lose and refuses to reveal
+        for (player, choice) in [(&p1, "rock"), (&p2, "scissors")] {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_choice(choice, 123),
+                amount: None,
+            });
+            app.execute_contract(player.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        }
+        assert_eq!(balance(&app, &p1), 950);
+        assert_eq!(balance(&app, &p2), 950);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+            game_id: 0,
+            value: "rock".to_string(),
+            nonce: 123,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        assert_eq!(balance(&app, &p1), 1_000);
+
+        // once the reveal window closes, player 2's bond goes to player 1
+        app.update_block(|block| block.height += 5);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound {
+            game_id: 0,
+        });
+        let res = app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "wasm-bond_slashed"
+            && e.attributes.iter().any(|a| a.key == "player" && a.value == p2.as_str())));
+        assert_eq!(balance(&app, &p1), 1_050);
+        assert_eq!(balance(&app, &p2), 950);
+
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.slashed_bonds, vec![(2, p2.clone(), Uint128::new(50))]);
+        assert_eq!(game.total_escrow, Uint128::zero());
+    }
+
+    #[test]
+    fn rock_paper_scissors_unpaid_slashed_bonds_stay_in_escrow() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(
+            &mut app,
+            Some(vec![
+                cw20::Cw20Coin { address: p1.to_string(), amount: Uint128::new(1_000) },
+                cw20::Cw20Coin { address: p2.to_string(), amount: Uint128::new(1_000) },
+            ]),
+        );
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 2,
+            reveal_duration: Some(cw_utils::Duration::Height(5)),
+            commit_bond: Some(Uint128::new(50)),
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+        // both players commit and neither reveals
+        for (player, choice) in [(&p1, "rock"), (&p2, "scissors")] {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                game_id: 0,
+                value: hash_choice(choice, 123),
+                amount: None,
+            });
+            app.execute_contract(player.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        }
+
+        // there is no treasury and nobody revealed, so the slashed bonds are not paid out and
+        // remain accounted for in the escrow
+        app.update_block(|block| block.height += 5);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound {
+            game_id: 0,
+        });
+        let res = app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        assert_eq!(res.events.iter().filter(|e| e.ty == "wasm-bond_slashed").count(), 2);
+
+        let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+            .wrap()
+            .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.slashed_bonds.len(), 2);
+        assert_eq!(game.total_escrow, Uint128::new(100));
+
+        let balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                p2e_contract.addr(),
+                &cw_p2e::msg::QueryMsg::Balance { address: rock_paper_scissors_contract.addr().to_string() },
+            )
+            .unwrap();
+        assert_eq!(balance.balance, game.total_escrow);
+    }
+
+    #[test]
+    fn rock_paper_scissors_ending_early_settles_open_bonds() {
+        let mut app = mock_app();
+        let owner = app.api().addr_make("owner");
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, rock_paper_scissors_contract) = setup_contracts(
+            &mut app,
+            Some(vec![
+                cw20::Cw20Coin { address: p1.to_string(), amount: Uint128::new(1_000) },
+                cw20::Cw20Coin { address: p2.to_string(), amount: Uint128::new(1_000) },
+            ]),
+        );
+
+        let config = cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            max_rounds: 3,
+            reveal_duration: Some(cw_utils::Duration::Height(5)),
+            commit_bond: Some(Uint128::new(50)),
+            ..Default::default()
+        };
+        for _ in 0..2 {
+            let msg =
+                crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                    config: config.clone(),
+                });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        }
+        join_game(&mut app, &rock_paper_scissors_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        for player in [&p1, &p2] {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
+                game_id: 1,
+                telegram_id: player.to_string(),
+                attestation: None,
+            });
+            app.execute_contract(player.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        }
+        for game_id in 0..2 {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+                game_id,
+            });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+
+            // both players commit and only player 1 reveals
+            for (player, choice) in [(&p1, "rock"), (&p2, "scissors")] {
+                let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                    game_id,
+                    value: hash_choice(choice, 123),
+                    amount: None,
+                });
+                app.execute_contract(player.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+            }
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                game_id,
+                value: "rock".to_string(),
+                nonce: 123,
+            });
+            app.execute_contract(p1.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap();
+        }
+
+        let balance = |app: &App, address: &Addr| {
+            let balance: cw20::BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: address.to_string() })
+                .unwrap();
+            balance.balance.u128()
+        };
+        let end_game = |app: &mut App, game_id: u64| {
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id });
+            app.execute_contract(owner.clone(), rock_paper_scissors_contract.addr(), &msg, &[]).unwrap()
+        };
+        let total_escrow = |app: &App, game_id: u64| {
+            let game: cw_game_lifecycle::state::Game<RockPaperScissorsConfig> = app
+                .wrap()
+                .query_wasm_smart(rock_paper_scissors_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id })
+                .unwrap();
+            game.total_escrow
+        };
+        assert_eq!(balance(&app, &p2), 900);
+
+        // the admin ends game 0 while player 2 can still reveal, so their bond is refunded
+        let res = end_game(&mut app, 0);
+        assert!(res.events.iter().any(|e| e.ty == "wasm-bond_refunded"
+            && e.attributes.iter().any(|a| a.key == "player" && a.value == p2.as_str())));
+        assert_eq!(balance(&app, &p1), 1_000);
+        assert_eq!(balance(&app, &p2), 950);
+        assert_eq!(total_escrow(&app, 0), Uint128::zero());
+
+        // game 1 is ended after player 2 let the reveal window pass, so their bond is slashed
+        app.update_block(|block| block.height += 5);
+        let res = end_game(&mut app, 1);
+        assert!(res.events.iter().any(|e| e.ty == "wasm-bond_slashed"
+            && e.attributes.iter().any(|a| a.key == "player" && a.value == p2.as_str())));
+        assert_eq!(balance(&app, &p1), 1_050);
+        assert_eq!(balance(&app, &p2), 950);
+        assert_eq!(total_escrow(&app, 1), Uint128::zero());
+        assert_eq!(balance(&app, &rock_paper_scissors_contract.addr()), 0);
+    }
+
+    #[test]
+    fn rock_paper_scissors_query_msg_merges_lifecycle_queries() {
+        use cosmwasm_schema::QueryResponses;
+
+        let lifecycle = cw_game_lifecycle::msg::QueryMsg::response_schemas().unwrap();
+        let game = crate::msg::QueryMsg::response_schemas().unwrap();
+        assert_eq!(game.len(), lifecycle.len() + 1);
+        assert!(game.contains_key("get_choices"));
+        for (query, schema) in lifecycle {
+            // only the game returned by `GetGame` carries the game's config extension
+            if query != "get_game" {
+                assert_eq!(game.get(&query), Some(&schema), "{query}");
+            }
+        }
+
+        let msg: crate::msg::QueryMsg =
+            cw_game_lifecycle::msg::QueryMsg::GetTopRatings { start_after: None, limit: Some(3) }.into();
+        let lifecycle: cw_game_lifecycle::msg::QueryMsg = msg.try_into().unwrap();
+        assert_eq!(lifecycle, cw_game_lifecycle::msg::QueryMsg::GetTopRatings { start_after: None, limit: Some(3) });
+        assert!(cw_game_lifecycle::msg::QueryMsg::try_from(crate::msg::QueryMsg::GetChoices { game_id: 0 }).is_err());
+    }
 }
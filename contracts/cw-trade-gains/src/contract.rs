@@ -1,12 +1,13 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::trade::TradeGains;
+use crate::trade::{TradeConfig, TradeGains};
 use cw_game_lifecycle::lifecycle::GameLifecycle;
+use cw_game_lifecycle::state::games;
 
 const CONTRACT_NAME: &str = "crates.io:cw-trade-gains";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -41,6 +42,10 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        _ => TradeGains::query(deps, env, msg.into()),
+        QueryMsg::GetPie { game_id } => {
+            let game = games::<TradeConfig>().load(deps.storage, game_id)?;
+            to_json_binary(&game.config.extension.pie_at(game.current_round))
+        }
+        _ => TradeGains::query(deps, env, msg.try_into()?),
     }
 }
@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coins, Addr, Uint128};
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
     use cw_multi_test::{error::AnyResult, App, ContractWrapper, Executor};
     use sha2::{Sha256, Digest};
     use hex;
 
+    use crate::trade::{BargainingMode, OverflowRule, TradeConfig};
+
     ///
     /// A wrapper around the code ID of the P2E token contract
     ///
@@ -114,6 +116,9 @@ mod tests {
                 base_url: "https://example.com".to_string(),
                 image_url: "https://example.com/image.png".to_string(),
                 token_contract: p2e_contract.addr(),
+                rating_config: None,
+                identity_contract: None,
+                attestation_pubkey: None,
             };
             app.instantiate_contract(code_id.0, sender, &init_msg, &[], label, None)
                 .map(Self::from)
@@ -176,6 +181,7 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
             });
             app.execute_contract(p.clone(), game_contract.addr(), &msg, &[]).unwrap();
         });
@@ -217,7 +223,7 @@ mod tests {
 
         assert_eq!(counter, 1);
 
-        let game: cw_game_lifecycle::state::Game = app
+        let game: cw_game_lifecycle::state::Game<TradeConfig> = app
             .wrap()
             .query_wasm_smart(trade_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
             .unwrap();
@@ -259,6 +265,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[])
@@ -268,6 +275,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), trade_game_contract.addr(), &msg, &[])
@@ -317,6 +325,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "1234567890".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[])
@@ -326,6 +335,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: "0987654321".to_string(),
+            attestation: None,
         });
         app
             .execute_contract(p2.clone(), trade_game_contract.addr(), &msg, &[])
@@ -367,6 +377,7 @@ mod tests {
             let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
                 game_id: 0,
                 telegram_id: p.to_string(),
+                attestation: None,
             });
             app.execute_contract(p.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
         });
@@ -375,6 +386,7 @@ mod tests {
         let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::JoinGame {
             game_id: 0,
             telegram_id: p3.to_string(),
+            attestation: None,
         });
         let res = app.execute_contract(p3.clone(), trade_game_contract.addr(), &msg, &[]);
         assert!(res.is_err());
@@ -456,17 +468,16 @@ mod tests {
             app.execute_contract(p2.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
 
             // check game status, should not be finished yet
-            let game_details: cw_game_lifecycle::state::Game = app
+            let game_details: cw_game_lifecycle::state::Game<TradeConfig> = app
                 .wrap()
                 .query_wasm_smart(trade_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
                 .unwrap();
             
-            // assert that the round is incremented
-            assert_eq!(game_details.current_round, current_round + 1);
-            
             if current_round < 3 {
                 // if this is not the last round, the game should be in progress
                 assert_eq!(game_details.status, cw_game_lifecycle::state::GameStatus::InProgress);
+                // assert that the round is incremented
+                assert_eq!(game_details.current_round, current_round + 1);
             } else {
                 // if this is the last round, the game should be finished
                 assert_eq!(game_details.status, cw_game_lifecycle::state::GameStatus::RoundsFinished);
@@ -572,17 +583,16 @@ mod tests {
             app.execute_contract(p2.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
 
             // check game status, should not be finished yet
-            let game_details: cw_game_lifecycle::state::Game = app
+            let game_details: cw_game_lifecycle::state::Game<TradeConfig> = app
                 .wrap()
                 .query_wasm_smart(trade_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
                 .unwrap();
             
-            // assert that the round is incremented
-            assert_eq!(game_details.current_round, current_round + 1);
-            
             if current_round < 3 {
                 // if this is not the last round, the game should be in progress
                 assert_eq!(game_details.status, cw_game_lifecycle::state::GameStatus::InProgress);
+                // assert that the round is incremented
+                assert_eq!(game_details.current_round, current_round + 1);
             } else {
                 // if this is the last round, the game should be finished
                 assert_eq!(game_details.status, cw_game_lifecycle::state::GameStatus::RoundsFinished);
@@ -611,4 +621,386 @@ mod tests {
         }).unwrap();
         assert_eq!(balance.balance, Uint128::new(0));
     }
+
+    #[test]
+    fn trade_contract_configurable_pie_and_overflow() {
+        // player 1 reveals their claim before player 2, returns the balance of each player
+        fn balances(extension: TradeConfig, claims: (&str, &str)) -> (u128, u128) {
+            let mut app = mock_app();
+            let p1 = app.api().addr_make("player_1");
+            let p2 = app.api().addr_make("player_2");
+            let (p2e_contract, trade_game_contract) = setup_contracts(&mut app, None);
+
+            let config = cw_game_lifecycle::state::GameConfig {
+                max_players: Some(2),
+                max_rounds: 1,
+                extension,
+                ..Default::default()
+            };
+            let msg =
+                crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                    config,
+                });
+            app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+            join_game(&mut app, &trade_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+                game_id: 0,
+            });
+            app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+
+            let moves = [(&p1, claims.0), (&p2, claims.1)];
+            for (player, claim) in moves {
+                let mut hasher = Sha256::new();
+                hasher.update(claim.as_bytes());
+                hasher.update(123u64.to_be_bytes());
+                let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+                    game_id: 0,
+                    value: hex::encode(hasher.finalize()),
+                    amount: None,
+                });
+                app.execute_contract(player.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+            }
+            for (player, claim) in moves {
+                let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+                    game_id: 0,
+                    value: claim.to_string(),
+                    nonce: 123,
+                });
+                app.execute_contract(player.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+            }
+
+            let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+                game_id: 0,
+            });
+            app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+
+            let balance = |player: &Addr| {
+                let balance: cw20::BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: player.to_string() })
+                    .unwrap();
+                balance.balance.u128()
+            };
+            (balance(&p1), balance(&p2))
+        }
+
+        let overflow = |overflow| TradeConfig { overflow, ..Default::default() };
+        // claims of 6 and 7 units exceed the default pie of 10
+        assert_eq!(balances(overflow(OverflowRule::Zero), ("6", "7")), (0, 0));
+        // 6 * 10 / 13 and 7 * 10 / 13 units, rounded down
+        assert_eq!(balances(overflow(OverflowRule::Proportional), ("6", "7")), (40, 50));
+        // player 1 revealed first and keeps their claim, player 2 gets the remaining 4 units
+        assert_eq!(balances(overflow(OverflowRule::FirstRevealer), ("6", "7")), (60, 40));
+
+        // a larger pie allows larger claims, paid with the game's multiplier
+        let config = TradeConfig {
+            pie: 20,
+            multiplier: Uint128::new(5),
+            ..Default::default()
+        };
+        assert_eq!(balances(config, ("12", "7")), (60, 35));
+    }
+
+    #[test]
+    fn trade_contract_validates_claims_against_the_pie() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, trade_game_contract) = setup_contracts(&mut app, None);
+
+        let create_game = |app: &mut App, config| {
+            let msg =
+                crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                    config,
+                });
+            app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[])
+        };
+
+        let err = create_game(&mut app, cw_game_lifecycle::state::GameConfig {
+            extension: TradeConfig { pie: 0, ..Default::default() },
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "pie must be greater than zero".to_string(),
+            })
+            .to_string()
+        );
+
+        // rewards of a game must fit in a Uint128
+        let err = create_game(&mut app, cw_game_lifecycle::state::GameConfig {
+            extension: TradeConfig { multiplier: Uint128::MAX, ..Default::default() },
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "multiplier is too large for the pie and number of rounds".to_string(),
+            })
+            .to_string()
+        );
+
+        // so must the forfeit reward of the rounds the other player forfeits
+        let err = create_game(&mut app, cw_game_lifecycle::state::GameConfig {
+            max_rounds: 1,
+            extension: TradeConfig { multiplier: Uint128::MAX / Uint128::new(10), ..Default::default() },
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "multiplier is too large for the pie and number of rounds".to_string(),
+            })
+            .to_string()
+        );
+
+        // default moves are claims too
+        let err = create_game(&mut app, cw_game_lifecycle::state::GameConfig {
+            absence_policy: cw_game_lifecycle::state::AbsencePolicy::DefaultMove { value: "11".to_string() },
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidDefaultMove {
+                value: "11".to_string(),
+            })
+            .to_string()
+        );
+
+        create_game(&mut app, cw_game_lifecycle::state::GameConfig {
+            max_players: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+        join_game(&mut app, &trade_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()]);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+
+        // claims cannot exceed the default pie of 10
+        let mut hasher = Sha256::new();
+        hasher.update("11".as_bytes());
+        hasher.update(123u64.to_be_bytes());
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+            game_id: 0,
+            value: hex::encode(hasher.finalize()),
+            amount: None,
+        });
+        for player in [&p1, &p2] {
+            app.execute_contract(player.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+        }
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::RevealRound {
+            game_id: 0,
+            value: "11".to_string(),
+            nonce: 123,
+        });
+        let err = app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidRevealChoice {
+                game_id: 0,
+                round: 1,
+            })
+            .to_string()
+        );
+    }
+
+    fn start_alternating_offers(
+        app: &mut App,
+        trade_game_contract: &TradeGameContract,
+        p2e_contract: &P2ETokenContract,
+        players: Vec<Addr>,
+        max_rounds: u8,
+    ) {
+        let config = cw_game_lifecycle::state::GameConfig {
+            min_players: 2,
+            max_players: Some(2),
+            max_rounds,
+            extension: TradeConfig {
+                mode: BargainingMode::AlternatingOffers { discount: Decimal::percent(50) },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config,
+            });
+        app.execute_contract(players[0].clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+        join_game(app, trade_game_contract, p2e_contract, players.clone());
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::StartGame {
+            game_id: 0,
+        });
+        app.execute_contract(players[0].clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+    }
+
+    fn play_turn(app: &mut App, trade_game_contract: &TradeGameContract, player: &Addr, value: &str) -> AnyResult<()> {
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::PlayTurn {
+            game_id: 0,
+            value: value.to_string(),
+        });
+        app.execute_contract(player.clone(), trade_game_contract.addr(), &msg, &[]).map(|_| ())
+    }
+
+    #[test]
+    fn trade_contract_alternating_offers() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, trade_game_contract) = setup_contracts(&mut app, None);
+        start_alternating_offers(&mut app, &trade_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()], 4);
+
+        let pie = |app: &App| {
+            let pie: Uint128 = app
+                .wrap()
+                .query_wasm_smart(trade_game_contract.addr(), &crate::msg::QueryMsg::GetPie { game_id: 0 })
+                .unwrap();
+            pie.u128()
+        };
+
+        // round 1: player 1 offers 3 of the 10 units and player 2 rejects it to counter offer
+        assert_eq!(pie(&app), 10);
+        play_turn(&mut app, &trade_game_contract, &p1, "3").unwrap();
+        play_turn(&mut app, &trade_game_contract, &p2, "reject").unwrap();
+
+        // round 2: the pie is halved and player 2 cannot offer more than is left
+        assert_eq!(pie(&app), 5);
+        let err = play_turn(&mut app, &trade_game_contract, &p2, "6").unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidTurn {
+                game_id: 0,
+                round: 2,
+            })
+            .to_string()
+        );
+        play_turn(&mut app, &trade_game_contract, &p2, "2").unwrap();
+        play_turn(&mut app, &trade_game_contract, &p1, "accept").unwrap();
+
+        // the accepted offer ends the game before the remaining rounds
+        let game: cw_game_lifecycle::state::Game<TradeConfig> = app
+            .wrap()
+            .query_wasm_smart(trade_game_contract.addr(), &crate::msg::QueryMsg::GetGame { game_id: 0 })
+            .unwrap();
+        assert_eq!(game.status, cw_game_lifecycle::state::GameStatus::RoundsFinished);
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+
+        // player 1 receives the 2 units offered and player 2 keeps the other 3
+        let balance = |player: &Addr| {
+            let balance: cw20::BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(p2e_contract.addr(), &cw_p2e::msg::QueryMsg::Balance { address: player.to_string() })
+                .unwrap();
+            balance.balance.u128()
+        };
+        assert_eq!(balance(&p1), 20);
+        assert_eq!(balance(&p2), 30);
+    }
+
+    #[test]
+    fn trade_contract_alternating_offers_without_agreement() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, trade_game_contract) = setup_contracts(&mut app, None);
+
+        // the pie cannot grow
+        let msg =
+            crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CreateGame {
+                config: cw_game_lifecycle::state::GameConfig {
+                    min_players: 2,
+                    max_players: Some(2),
+                    extension: TradeConfig {
+                        mode: BargainingMode::AlternatingOffers { discount: Decimal::percent(110) },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            });
+        let err = app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "discount must be greater than zero and at most one".to_string(),
+            })
+            .to_string()
+        );
+
+        start_alternating_offers(&mut app, &trade_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()], 2);
+
+        // offers are played in the open, not committed
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::CommitRound {
+            game_id: 0,
+            value: "5".to_string(),
+            amount: None,
+        });
+        let err = app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::GameLifecycle(cw_game_lifecycle::ContractError::TurnBasedGame {
+                game_id: 0,
+            })
+            .to_string()
+        );
+
+        // both offers are rejected, so nobody gets anything once the rounds run out
+        play_turn(&mut app, &trade_game_contract, &p1, "5").unwrap();
+        play_turn(&mut app, &trade_game_contract, &p2, "reject").unwrap();
+        play_turn(&mut app, &trade_game_contract, &p2, "1").unwrap();
+        play_turn(&mut app, &trade_game_contract, &p1, "reject").unwrap();
+
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        let res = app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+        let scores: Vec<&str> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .flat_map(|e| e.attributes.iter().filter(|a| a.key == "score"))
+            .map(|a| a.value.as_str())
+            .collect();
+        assert_eq!(scores, vec!["0", "0"]);
+    }
+
+    #[test]
+    fn trade_contract_alternating_offers_missed_turn() {
+        let mut app = mock_app();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, trade_game_contract) = setup_contracts(&mut app, None);
+        start_alternating_offers(&mut app, &trade_game_contract, &p2e_contract, vec![p1.clone(), p2.clone()], 1);
+
+        // player 2 does not answer the offer before the round expires
+        play_turn(&mut app, &trade_game_contract, &p1, "3").unwrap();
+        app.update_block(|block| block.height += 101);
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound {
+            game_id: 0,
+        });
+        app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+
+        // the missed turn rejects the offer, so the proposer does not win the forfeited round
+        let msg = crate::msg::ExecuteMsg::Lifecycle(cw_game_lifecycle::msg::ExecuteMsg::EndGame {
+            game_id: 0,
+        });
+        let res = app.execute_contract(p1.clone(), trade_game_contract.addr(), &msg, &[]).unwrap();
+        let scores: Vec<&str> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .flat_map(|e| e.attributes.iter().filter(|a| a.key == "score"))
+            .map(|a| a.value.as_str())
+            .collect();
+        assert_eq!(scores, vec!["0", "0"]);
+    }
 }
@@ -1,10 +1,17 @@
 use std::{collections::HashMap};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_game_lifecycle::{lifecycle::GameLifecycle, state::Game, ContractError as LifecycleError};
+use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{AbsencePolicy, Game, GameConfig, GameRound},
+    ContractError as LifecycleError,
+};
 
 use crate::ContractError;
 
+const ACCEPT: &str = "accept";
+const REJECT: &str = "reject";
+
 #[cw_serde]
 pub struct TradeGainsChoice(u8);
 
@@ -19,58 +26,230 @@ impl TryFrom<String> for TradeGainsChoice {
     }
 }
 
+/// Trade gains specific parameters of a game
+#[cw_serde]
+pub struct TradeConfig {
+    pub pie: u8, // units the players claim a share of every round
+    pub multiplier: Uint128, // tokens paid per unit claimed
+    pub overflow: OverflowRule, // only used by `BargainingMode::Demand`
+    pub mode: BargainingMode,
+}
+
+impl Default for TradeConfig {
+    fn default() -> Self {
+        Self {
+            pie: 10,
+            multiplier: Uint128::new(10),
+            overflow: OverflowRule::Zero,
+            mode: BargainingMode::Demand,
+        }
+    }
+}
+
+/// How the players of a game bargain over the pie
+#[cw_serde]
+pub enum BargainingMode {
+    Demand, // both players claim a share of the pie at the same time every round
+    // players take turns offering a split of a pie that shrinks by the discount every round, the
+    // game ends as soon as an offer is accepted
+    AlternatingOffers { discount: Decimal },
+}
+
+/// How a round is paid when the players claim more than the pie
+#[cw_serde]
+pub enum OverflowRule {
+    Zero, // nobody gets anything
+    Proportional, // the pie is split in proportion to the claims, rounding down
+    FirstRevealer, // the first revealer gets their claim and the other player what is left
+}
+
+impl TradeConfig {
+    /// Units each player of a round gets for their claims, in reveal order
+    pub fn shares(&self, (first, second): (u8, u8)) -> (Uint128, Uint128) {
+        let (pie, first, second) = (
+            Uint128::from(self.pie),
+            Uint128::from(first),
+            Uint128::from(second),
+        );
+        if first + second <= pie {
+            return (first, second);
+        }
+        match self.overflow {
+            OverflowRule::Zero => (Uint128::zero(), Uint128::zero()),
+            OverflowRule::Proportional => (
+                first.multiply_ratio(pie, first + second),
+                second.multiply_ratio(pie, first + second),
+            ),
+            OverflowRule::FirstRevealer => (first, second.min(pie - first)),
+        }
+    }
+
+    /// Units to split in a round, the pie of alternating offers games is discounted every round
+    pub fn pie_at(&self, round: u8) -> Uint128 {
+        match &self.mode {
+            BargainingMode::Demand => Uint128::from(self.pie),
+            BargainingMode::AlternatingOffers { discount } => {
+                Uint128::from(self.pie).mul_floor(discount.pow(round.saturating_sub(1) as u32))
+            }
+        }
+    }
+
+    fn is_valid_claim(&self, value: &str) -> bool {
+        TradeGainsChoice::try_from(value.to_string()).is_ok_and(|choice| choice.0 <= self.pie)
+    }
+}
+
 #[cw_serde]
 pub struct TradeGains;
 
+impl TradeGains {
+    /// The offer of an alternating offers round if the responder accepted it, the first turn of
+    /// the round is the proposer's offer to the responder and the second the responder's answer
+    fn accepted_offer(round: &GameRound) -> Option<(Addr, Uint128, Addr)> {
+        let [(proposer, offer), (responder, answer)] = round.reveals.as_slice() else {
+            return None;
+        };
+        let offer = TradeGainsChoice::try_from(offer.clone()).ok()?;
+        (answer == ACCEPT).then(|| (proposer.clone(), Uint128::from(offer.0), responder.clone()))
+    }
+}
+
 impl GameLifecycle for TradeGains {
+    type Extension = TradeConfig;
+
     fn is_valid_reveal_choice(value: &String) -> bool {
-        // 0-10 is valid
-        let choice = TradeGainsChoice::try_from(value.to_string());
-        choice.is_ok() && choice.unwrap().0 <= 10
+        // claims are checked against the game's pie in `is_valid_move`
+        TradeGainsChoice::try_from(value.to_string()).is_ok()
+    }
+
+    fn is_turn_based(config: &GameConfig<TradeConfig>) -> bool {
+        // offers are answered after they are seen
+        matches!(config.extension.mode, BargainingMode::AlternatingOffers { .. })
+    }
+
+    fn is_valid_turn(config: &GameConfig<TradeConfig>, round: &GameRound, value: &String) -> bool {
+        // the proposer offers part of the round's pie, the responder accepts it or rejects it to
+        // make a counter offer in the next round
+        match round.reveals.is_empty() {
+            true => TradeGainsChoice::try_from(value.clone())
+                .is_ok_and(|offer| Uint128::from(offer.0) <= config.extension.pie_at(round.id)),
+            false => value == ACCEPT || value == REJECT,
+        }
+    }
+
+    fn is_decided(game: &Game<TradeConfig>) -> bool {
+        game.scored_rounds().any(|round| Self::accepted_offer(round).is_some())
+    }
+
+    fn is_valid_move(
+        _deps: Deps,
+        game: &Game<TradeConfig>,
+        _player: &Addr,
+        value: &String,
+    ) -> StdResult<bool> {
+        // 0 up to the pie is valid
+        Ok(game.config.extension.is_valid_claim(value))
+    }
+
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<TradeConfig>,
+    ) -> Result<(), LifecycleError> {
+        if config.extension.pie == 0 {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("pie must be greater than zero"),
+            });
+        } else if config.extension.multiplier.is_zero() {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("multiplier must be greater than zero"),
+            });
+        }
+
+        // a player can receive at most the whole pie or the forfeit reward every round, which
+        // must not overflow
+        let max_winnings = config
+            .extension
+            .multiplier
+            .checked_mul(Uint128::from(config.extension.pie))
+            .and_then(|round| round.checked_add(Self::forfeit_reward(config, 0)))
+            .and_then(|round| round.checked_mul(Uint128::from(config.max_rounds)));
+        if max_winnings.is_err() {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("multiplier is too large for the pie and number of rounds"),
+            });
+        }
+        if let BargainingMode::AlternatingOffers { discount } = &config.extension.mode {
+            if discount.is_zero() || *discount > Decimal::one() {
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("discount must be greater than zero and at most one"),
+                });
+            } else if config.min_players != 2 || config.max_players != Some(2) {
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("alternating offers are played by two players"),
+                });
+            } else if matches!(config.absence_policy, AbsencePolicy::DefaultMove { .. }) {
+                return Err(LifecycleError::InvalidGameConfig {
+                    reason: String::from("alternating offers cannot have default moves"),
+                });
+            }
+        }
+        if let AbsencePolicy::DefaultMove { value } = &config.absence_policy {
+            if !config.extension.is_valid_claim(value) {
+                return Err(LifecycleError::InvalidDefaultMove {
+                    value: value.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn forfeit_reward(config: &GameConfig<TradeConfig>, _round: u8) -> Uint128 {
+        match config.extension.mode {
+            // the revealer of a forfeited round gets half the pie, as if the claims had split it
+            // evenly
+            BargainingMode::Demand => {
+                (Uint128::from(config.extension.pie) / Uint128::new(2)) * config.extension.multiplier
+            }
+            // a responder who misses their turn rejects the offer, so the proposer gets nothing
+            BargainingMode::AlternatingOffers { .. } => Uint128::zero(),
+        }
     }
 
     fn calculate_rewards_and_winners(
-        game: &mut Game,
+        _deps: Deps,
+        game: &mut Game<TradeConfig>,
     ) -> Result<bool, LifecycleError> {
+        let config = game.config.extension.clone();
         let mut winnings: HashMap<Addr, Uint128> = HashMap::from([
             (game.players[0].0.clone(), Uint128::zero()),
             (game.players[1].0.clone(), Uint128::zero()),
         ]);
 
-        game.rounds.iter().for_each(|round| {
+        if let BargainingMode::AlternatingOffers { .. } = config.mode {
+            // only the accepted offer is paid, out of the pie left in its round
+            let accepted = game
+                .scored_rounds()
+                .find_map(|round| Self::accepted_offer(round).map(|offer| (round.id, offer)));
+            if let Some((round, (proposer, offer, responder))) = accepted {
+                let pie = config.pie_at(round);
+                *winnings.entry(proposer).or_default() += (pie - offer) * config.multiplier;
+                *winnings.entry(responder).or_default() += offer * config.multiplier;
+            }
+            game.scores = winnings;
+            return Ok(true);
+        }
+
+        game.scored_rounds().for_each(|round| {
             let reveals = round.reveals.as_slice();
             
-            match (reveals.get(0), reveals.get(1)) {
-                (Some(p1_reveal), Some(p2_reveal)) => {
-                    let p1_choice = TradeGainsChoice::try_from(p1_reveal.1.clone()).unwrap();
-                    let p2_choice = TradeGainsChoice::try_from(p2_reveal.1.clone()).unwrap();
-
-                    if p1_choice.0 + p2_choice.0 > 10 {
-                        return;
-                    }
-
-                    // rewards are 10x the selected amount
-                    let p1_reward = Uint128::from(p1_choice.0) * Uint128::from(10u128);
-                    let p2_reward = Uint128::from(p2_choice.0) * Uint128::from(10u128);
-
-                    winnings.insert(reveals[0].0.clone(), winnings.get(&p1_reveal.0).unwrap() + p1_reward);
-                    winnings.insert(reveals[1].0.clone(), winnings.get(&p2_reveal.0).unwrap() + p2_reward);
-                }
-                // p1 revealed, p2 did not reveal, p1 gets 100
-                (Some(p1_reveal), None) => {
-                    winnings.insert(
-                        p1_reveal.0.clone(),
-                        winnings.get(&p1_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                // p2 revealed, p1 did not reveal, p2 gets 100
-                (None, Some(p2_reveal)) => {
-                    winnings.insert(
-                        p2_reveal.0.clone(),
-                        winnings.get(&p2_reveal.0).unwrap() + Uint128::from(100u128),
-                    );
-                }
-                _ => {}
+            if let (Some(p1_reveal), Some(p2_reveal)) = (reveals.first(), reveals.get(1)) {
+                let p1_choice = TradeGainsChoice::try_from(p1_reveal.1.clone()).unwrap();
+                let p2_choice = TradeGainsChoice::try_from(p2_reveal.1.clone()).unwrap();
+                let (p1_share, p2_share) = config.shares((p1_choice.0, p2_choice.0));
+
+                // rewards are the multiplier times the units received
+                *winnings.entry(p1_reveal.0.clone()).or_default() += p1_share * config.multiplier;
+                *winnings.entry(p2_reveal.0.clone()).or_default() += p2_share * config.multiplier;
             }
         });
 
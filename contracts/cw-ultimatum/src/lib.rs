@@ -0,0 +1,9 @@
+pub mod contract;
+mod error;
+pub mod helpers;
+pub mod msg;
+pub mod state;
+pub mod ultimatum;
+mod test;
+
+pub use crate::error::ContractError;
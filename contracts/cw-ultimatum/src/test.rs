@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Empty, Uint128};
+    use cw_game_lifecycle::state::{AbsencePolicy, GameConfig};
+    use cw_game_lifecycle::test_utils::{
+        self, assert_lifecycle_error, balance_of, commit, create_game, execute_lifecycle, game_config,
+        play_turn, start_game,
+    };
+    use cw_multi_test::{App, Contract, ContractWrapper};
+
+    use crate::ultimatum::UltimatumConfig;
+
+    fn ultimatum() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        ))
+    }
+
+    #[test]
+    fn ultimatum_roles_alternate_and_rejections_pay_nothing() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, ultimatum(), test_utils::instantiate_msg);
+        start_game(&mut app, &game_contract, &[&p1, &p2], game_config(2, 2, UltimatumConfig::default()));
+
+        // round 1: player 1 proposes and the responder cannot answer before the offer is made
+        let err = play_turn(&mut app, &game_contract, &p2, "accept").unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::NotPlayerTurn { game_id: 0, player: p2.clone() },
+        );
+        play_turn(&mut app, &game_contract, &p1, "30").unwrap();
+        play_turn(&mut app, &game_contract, &p2, "accept").unwrap();
+
+        // round 2: the roles swap and player 1 rejects player 2's offer
+        let err = play_turn(&mut app, &game_contract, &p1, "50").unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::NotPlayerTurn { game_id: 0, player: p1.clone() },
+        );
+        play_turn(&mut app, &game_contract, &p2, "10").unwrap();
+        play_turn(&mut app, &game_contract, &p1, "reject").unwrap();
+
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        assert_eq!(balance_of(&app, &p2e_contract, &p1), Uint128::new(70));
+        assert_eq!(balance_of(&app, &p2e_contract, &p2), Uint128::new(30));
+    }
+
+    #[test]
+    fn ultimatum_rejects_invalid_offers_and_commits() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (_, game_contract) = test_utils::setup_contracts(&mut app, ultimatum(), test_utils::instantiate_msg);
+
+        // the game is played head to head
+        let err = create_game(&mut app, &game_contract, &p1, GameConfig::<UltimatumConfig>::default()).unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "ultimatum games are played by two players".to_string(),
+            },
+        );
+
+        // turns cannot be filled in with a default
+        let config = GameConfig {
+            absence_policy: AbsencePolicy::DefaultMove { value: "reject".to_string() },
+            ..game_config(2, 1, UltimatumConfig::default())
+        };
+        let err = create_game(&mut app, &game_contract, &p1, config).unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::InvalidGameConfig {
+                reason: "ultimatum games cannot have default moves".to_string(),
+            },
+        );
+
+        start_game(&mut app, &game_contract, &[&p1, &p2], game_config(2, 1, UltimatumConfig::default()));
+
+        // moves are played in the open, not committed
+        let err = commit(&mut app, &game_contract, &p1, "30").unwrap_err();
+        assert_lifecycle_error(err, cw_game_lifecycle::ContractError::TurnBasedGame { game_id: 0 });
+
+        // offers cannot exceed the pot and answers must accept or reject
+        let invalid_turn =
+            || cw_game_lifecycle::ContractError::InvalidTurn { game_id: 0, round: 1 };
+        let err = play_turn(&mut app, &game_contract, &p1, "101").unwrap_err();
+        assert_lifecycle_error(err, invalid_turn());
+        play_turn(&mut app, &game_contract, &p1, "100").unwrap();
+        let err = play_turn(&mut app, &game_contract, &p2, "30").unwrap_err();
+        assert_lifecycle_error(err, invalid_turn());
+        play_turn(&mut app, &game_contract, &p2, "accept").unwrap();
+    }
+
+    #[test]
+    fn ultimatum_missed_answers_are_rejections() {
+        let mut app = App::default();
+        let p1 = app.api().addr_make("player_1");
+        let p2 = app.api().addr_make("player_2");
+        let (p2e_contract, game_contract) = test_utils::setup_contracts(&mut app, ultimatum(), test_utils::instantiate_msg);
+        start_game(&mut app, &game_contract, &[&p1, &p2], game_config(2, 1, UltimatumConfig::default()));
+
+        play_turn(&mut app, &game_contract, &p1, "40").unwrap();
+
+        // once the commit window passes, the round closes without waiting for a reveal window
+        app.update_block(|block| block.height += 101);
+        let err = play_turn(&mut app, &game_contract, &p2, "accept").unwrap_err();
+        assert_lifecycle_error(
+            err,
+            cw_game_lifecycle::ContractError::RoundExpired { game_id: 0, round: 1 },
+        );
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::AdvanceRound { game_id: 0 };
+        execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+
+        // the responder forfeits the round, which pays nothing like a rejected offer
+        let msg = cw_game_lifecycle::msg::ExecuteMsg::EndGame { game_id: 0 };
+        let res = execute_lifecycle(&mut app, &game_contract, &p1, msg).unwrap();
+        let scores: Vec<&str> = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-game_winnings")
+            .flat_map(|e| e.attributes.iter().filter(|a| a.key == "score"))
+            .map(|a| a.value.as_str())
+            .collect();
+        assert_eq!(scores, vec!["0", "0"]);
+        assert_eq!(balance_of(&app, &p2e_contract, &p1), Uint128::zero());
+    }
+}
@@ -0,0 +1,118 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Uint128};
+use cw_game_lifecycle::{
+    lifecycle::GameLifecycle,
+    state::{AbsencePolicy, Game, GameConfig, GameRound},
+    ContractError as LifecycleError,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const ACCEPT: &str = "accept";
+const REJECT: &str = "reject";
+
+#[cw_serde]
+pub struct Ultimatum;
+
+/// Ultimatum specific parameters of a game
+#[cw_serde]
+pub struct UltimatumConfig {
+    pub pot: Uint128, // tokens the proposer splits with the responder every round
+}
+
+impl Default for UltimatumConfig {
+    fn default() -> Self {
+        Self {
+            pot: Uint128::new(100),
+        }
+    }
+}
+
+impl Ultimatum {
+    /// Payoffs of a round, whose first turn is the proposer's offer to the responder and the
+    /// second the responder's answer. A rejected offer pays nothing to either player.
+    fn round_payoffs(round: &GameRound, pot: Uint128) -> Vec<(Addr, Uint128)> {
+        let [(proposer, offer), (responder, answer)] = round.reveals.as_slice() else {
+            return vec![];
+        };
+        match (Uint128::from_str(offer), answer.as_str()) {
+            (Ok(offer), ACCEPT) if offer <= pot => {
+                vec![(proposer.clone(), pot - offer), (responder.clone(), offer)]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl GameLifecycle for Ultimatum {
+    type Extension = UltimatumConfig;
+
+    fn is_turn_based(_config: &GameConfig<UltimatumConfig>) -> bool {
+        // the responder has to see the offer before answering it
+        true
+    }
+
+    fn is_valid_reveal_choice(value: &String) -> bool {
+        Uint128::from_str(value).is_ok() || value == ACCEPT || value == REJECT
+    }
+
+    fn is_valid_turn(
+        config: &GameConfig<UltimatumConfig>,
+        round: &GameRound,
+        value: &String,
+    ) -> bool {
+        // the proposer offers part of the pot, the responder accepts or rejects it
+        match round.reveals.is_empty() {
+            true => Uint128::from_str(value).is_ok_and(|offer| offer <= config.extension.pot),
+            false => value == ACCEPT || value == REJECT,
+        }
+    }
+
+    fn validate_config(
+        _deps: Deps,
+        config: &GameConfig<UltimatumConfig>,
+    ) -> Result<(), LifecycleError> {
+        if config.extension.pot.is_zero() {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("pot must be greater than zero"),
+            });
+        } else if config.min_players != 2 || config.max_players != Some(2) {
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("ultimatum games are played by two players"),
+            });
+        } else if matches!(config.absence_policy, AbsencePolicy::DefaultMove { .. }) {
+            // a single default cannot be both an offer and an answer
+            return Err(LifecycleError::InvalidGameConfig {
+                reason: String::from("ultimatum games cannot have default moves"),
+            });
+        }
+        Ok(())
+    }
+
+    fn forfeit_reward(_config: &GameConfig<UltimatumConfig>, _round: u8) -> Uint128 {
+        // a responder who misses their turn rejects the offer, so the proposer gets nothing
+        Uint128::zero()
+    }
+
+    fn calculate_rewards_and_winners(
+        _deps: Deps,
+        game: &mut Game<UltimatumConfig>,
+    ) -> Result<bool, LifecycleError> {
+        let pot = game.config.extension.pot;
+        let mut winnings: HashMap<Addr, Uint128> = game
+            .players
+            .iter()
+            .map(|(player, _)| (player.clone(), Uint128::zero()))
+            .collect();
+
+        // players take turns proposing, see `GameLifecycle::turn_order`
+        for round in game.scored_rounds() {
+            for (player, payoff) in Self::round_payoffs(round, pot) {
+                *winnings.entry(player).or_default() += payoff;
+            }
+        }
+
+        game.scores = winnings;
+        Ok(true)
+    }
+}
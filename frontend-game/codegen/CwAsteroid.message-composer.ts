@@ -0,0 +1,38 @@
+/**
+* This file was automatically generated by @cosmwasm/ts-codegen@1.11.1.
+* DO NOT MODIFY IT BY HAND. Instead, modify the source JSONSchema file,
+* and run the @cosmwasm/ts-codegen generate command to regenerate this file.
+*/
+
+import { Coin } from "@cosmjs/amino";
+import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
+import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
+import { toUtf8 } from "@cosmjs/encoding";
+import { Uint128, Binary, Addr, InstantiateMsg, RatingConfig, ScoreTier, ExecuteMsg, ExecuteMsgForAsteroidConfig, AbsencePolicy, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForAsteroidConfig, AsteroidConfig, Handicap, TelegramAttestation, RelayPayload, QueryMsg, Config, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForAsteroidConfig, GameRound, ArrayOfHighScore, HighScore, ArrayOfTupleOfAddrAndStringAndUint128, NullableHighScore, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./CwAsteroid.types";
+export interface CwAsteroidMsg {
+  contractAddress: string;
+  sender: string;
+  lifecycle: (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+}
+export class CwAsteroidMsgComposer implements CwAsteroidMsg {
+  sender: string;
+  contractAddress: string;
+  constructor(sender: string, contractAddress: string) {
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lifecycle = this.lifecycle.bind(this);
+  }
+  lifecycle = (executeMsgForAsteroidConfig: ExecuteMsgForAsteroidConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          lifecycle: executeMsgForAsteroidConfig
+        })),
+        funds: _funds
+      })
+    };
+  };
+}
\ No newline at end of file
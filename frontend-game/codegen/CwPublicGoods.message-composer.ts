@@ -4,4 +4,35 @@
 * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
 */
 
-import { InstantiateMsg, ExecuteMsg, QueryMsg } from "./CwPublicGoods.types";
\ No newline at end of file
+import { Coin } from "@cosmjs/amino";
+import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
+import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
+import { toUtf8 } from "@cosmjs/encoding";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForPublicGoodsConfig, AbsencePolicy, Uint128, Duration, Decimal, Expiration, Timestamp, Uint64, RelayAction, GameConfigForPublicGoodsConfig, PublicGoodsConfig, Punishment, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForPublicGoodsConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, RatingResponse, StageResponse, ArrayOfRatingResponse } from "./CwPublicGoods.types";
+export interface CwPublicGoodsMsg {
+  contractAddress: string;
+  sender: string;
+  lifecycle: (executeMsgForPublicGoodsConfig: ExecuteMsgForPublicGoodsConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+}
+export class CwPublicGoodsMsgComposer implements CwPublicGoodsMsg {
+  sender: string;
+  contractAddress: string;
+  constructor(sender: string, contractAddress: string) {
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lifecycle = this.lifecycle.bind(this);
+  }
+  lifecycle = (executeMsgForPublicGoodsConfig: ExecuteMsgForPublicGoodsConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          lifecycle: executeMsgForPublicGoodsConfig
+        })),
+        funds: _funds
+      })
+    };
+  };
+}
\ No newline at end of file
@@ -4,4 +4,35 @@
 * and run the @cosmwasm/ts-codegen generate command to regenerate this file.
 */
 
-import { InstantiateMsg, ExecuteMsg, QueryMsg } from "./CwTradeGains.types";
\ No newline at end of file
+import { Coin } from "@cosmjs/amino";
+import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
+import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
+import { toUtf8 } from "@cosmjs/encoding";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, ExecuteMsgForTradeConfig, AbsencePolicy, Uint128, Duration, BargainingMode, Decimal, OverflowRule, Expiration, Timestamp, Uint64, RelayAction, GameConfigForTradeConfig, TradeConfig, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForTradeConfig, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, RatingResponse, ArrayOfRatingResponse } from "./CwTradeGains.types";
+export interface CwTradeGainsMsg {
+  contractAddress: string;
+  sender: string;
+  lifecycle: (executeMsgForTradeConfig: ExecuteMsgForTradeConfig, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+}
+export class CwTradeGainsMsgComposer implements CwTradeGainsMsg {
+  sender: string;
+  contractAddress: string;
+  constructor(sender: string, contractAddress: string) {
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.lifecycle = this.lifecycle.bind(this);
+  }
+  lifecycle = (executeMsgForTradeConfig: ExecuteMsgForTradeConfig, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          lifecycle: executeMsgForTradeConfig
+        })),
+        funds: _funds
+      })
+    };
+  };
+}
\ No newline at end of file
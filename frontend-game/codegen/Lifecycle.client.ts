@@ -6,26 +6,60 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Addr, InstantiateMsg, ExecuteMsg, Uint128, GameConfig, QueryMsg, GameRoundStatus, GameRound, GameStatus, Game, Uint64, ArrayOfTupleOfAddrAndStringAndUint128 } from "./Lifecycle.types";
+import { Binary, Addr, InstantiateMsg, RatingConfig, ExecuteMsg, AbsencePolicy, Uint128, Duration, Expiration, Timestamp, Uint64, RelayAction, GameConfigForEmpty, Empty, TelegramAttestation, RelayPayload, QueryMsg, GameRoundStatus, CurrentRoundResponse, NullableDelegation, Delegation, GameStatus, GameForEmpty, GameRound, ArrayOfTupleOfAddrAndStringAndUint128, PlayerStats, Decimal, RatingResponse, ArrayOfRatingResponse } from "./Lifecycle.types";
 export interface LifecycleReadOnlyInterface {
   contractAddress: string;
   getGame: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<Game>;
+  }) => Promise<GameForEmpty>;
   getLeaderboard: () => Promise<ArrayOfTupleOfAddrAndStringAndUint128>;
   getCurrentRound: ({
     gameId
   }: {
     gameId: number;
-  }) => Promise<GameRound>;
+  }) => Promise<CurrentRoundResponse>;
   getGameStatus: ({
     gameId
   }: {
     gameId: number;
   }) => Promise<GameStatus>;
   getGamesCount: () => Promise<Uint64>;
+  getPlayerStats: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<PlayerStats>;
+  getPlayerStatsByTelegramId: ({
+    telegramId
+  }: {
+    telegramId: string;
+  }) => Promise<PlayerStats>;
+  getDelegation: ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }) => Promise<NullableDelegation>;
+  getRelayNonce: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<Uint64>;
+  getRating: ({
+    player
+  }: {
+    player: Addr;
+  }) => Promise<RatingResponse>;
+  getTopRatings: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }) => Promise<ArrayOfRatingResponse>;
 }
 export class LifecycleQueryClient implements LifecycleReadOnlyInterface {
   client: CosmWasmClient;
@@ -38,12 +72,18 @@ export class LifecycleQueryClient implements LifecycleReadOnlyInterface {
     this.getCurrentRound = this.getCurrentRound.bind(this);
     this.getGameStatus = this.getGameStatus.bind(this);
     this.getGamesCount = this.getGamesCount.bind(this);
+    this.getPlayerStats = this.getPlayerStats.bind(this);
+    this.getPlayerStatsByTelegramId = this.getPlayerStatsByTelegramId.bind(this);
+    this.getDelegation = this.getDelegation.bind(this);
+    this.getRelayNonce = this.getRelayNonce.bind(this);
+    this.getRating = this.getRating.bind(this);
+    this.getTopRatings = this.getTopRatings.bind(this);
   }
   getGame = async ({
     gameId
   }: {
     gameId: number;
-  }): Promise<Game> => {
+  }): Promise<GameForEmpty> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_game: {
         game_id: gameId
@@ -59,7 +99,7 @@ export class LifecycleQueryClient implements LifecycleReadOnlyInterface {
     gameId
   }: {
     gameId: number;
-  }): Promise<GameRound> => {
+  }): Promise<CurrentRoundResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_current_round: {
         game_id: gameId
@@ -82,6 +122,78 @@ export class LifecycleQueryClient implements LifecycleReadOnlyInterface {
       get_games_count: {}
     });
   };
+  getPlayerStats = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats: {
+        player
+      }
+    });
+  };
+  getPlayerStatsByTelegramId = async ({
+    telegramId
+  }: {
+    telegramId: string;
+  }): Promise<PlayerStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_player_stats_by_telegram_id: {
+        telegram_id: telegramId
+      }
+    });
+  };
+  getDelegation = async ({
+    delegate,
+    player
+  }: {
+    delegate: Addr;
+    player: Addr;
+  }): Promise<NullableDelegation> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegation: {
+        delegate,
+        player
+      }
+    });
+  };
+  getRelayNonce = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<Uint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_relay_nonce: {
+        player
+      }
+    });
+  };
+  getRating = async ({
+    player
+  }: {
+    player: Addr;
+  }): Promise<RatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_rating: {
+        player
+      }
+    });
+  };
+  getTopRatings = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }): Promise<ArrayOfRatingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_top_ratings: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface LifecycleInterface extends LifecycleReadOnlyInterface {
   contractAddress: string;
@@ -89,7 +201,7 @@ export interface LifecycleInterface extends LifecycleReadOnlyInterface {
   createGame: ({
     config
   }: {
-    config: GameConfig;
+    config: GameConfigForEmpty;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   startGame: ({
     gameId
@@ -97,9 +209,11 @@ export interface LifecycleInterface extends LifecycleReadOnlyInterface {
     gameId: number;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   joinGame: ({
+    attestation,
     gameId,
     telegramId
   }: {
+    attestation?: TelegramAttestation;
     gameId: number;
     telegramId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
@@ -112,7 +226,7 @@ export interface LifecycleInterface extends LifecycleReadOnlyInterface {
     gameId: number;
     value: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  commitRoundAsAdmin: ({
+  commitRoundAsDelegate: ({
     amount,
     gameId,
     player,
@@ -132,6 +246,54 @@ export interface LifecycleInterface extends LifecycleReadOnlyInterface {
     nonce: number;
     value: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  revealRoundAsDelegate: ({
+    gameId,
+    nonce,
+    player,
+    value
+  }: {
+    gameId: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  playTurn: ({
+    gameId,
+    value
+  }: {
+    gameId: number;
+    value: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  grantDelegate: ({
+    delegate,
+    expiresAt,
+    gameId,
+    maxActions
+  }: {
+    delegate: Addr;
+    expiresAt: Expiration;
+    gameId?: number;
+    maxActions: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeDelegate: ({
+    delegate
+  }: {
+    delegate: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  relaySigned: ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  advanceRound: ({
+    gameId
+  }: {
+    gameId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   endGame: ({
     gameId
   }: {
@@ -151,14 +313,20 @@ export class LifecycleClient extends LifecycleQueryClient implements LifecycleIn
     this.startGame = this.startGame.bind(this);
     this.joinGame = this.joinGame.bind(this);
     this.commitRound = this.commitRound.bind(this);
-    this.commitRoundAsAdmin = this.commitRoundAsAdmin.bind(this);
+    this.commitRoundAsDelegate = this.commitRoundAsDelegate.bind(this);
     this.revealRound = this.revealRound.bind(this);
+    this.revealRoundAsDelegate = this.revealRoundAsDelegate.bind(this);
+    this.playTurn = this.playTurn.bind(this);
+    this.grantDelegate = this.grantDelegate.bind(this);
+    this.revokeDelegate = this.revokeDelegate.bind(this);
+    this.relaySigned = this.relaySigned.bind(this);
+    this.advanceRound = this.advanceRound.bind(this);
     this.endGame = this.endGame.bind(this);
   }
   createGame = async ({
     config
   }: {
-    config: GameConfig;
+    config: GameConfigForEmpty;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       create_game: {
@@ -178,14 +346,17 @@ export class LifecycleClient extends LifecycleQueryClient implements LifecycleIn
     }, fee, memo, _funds);
   };
   joinGame = async ({
+    attestation,
     gameId,
     telegramId
   }: {
+    attestation?: TelegramAttestation;
     gameId: number;
     telegramId: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       join_game: {
+        attestation,
         game_id: gameId,
         telegram_id: telegramId
       }
@@ -208,7 +379,7 @@ export class LifecycleClient extends LifecycleQueryClient implements LifecycleIn
       }
     }, fee, memo, _funds);
   };
-  commitRoundAsAdmin = async ({
+  commitRoundAsDelegate = async ({
     amount,
     gameId,
     player,
@@ -220,7 +391,7 @@ export class LifecycleClient extends LifecycleQueryClient implements LifecycleIn
     value: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      commit_round_as_admin: {
+      commit_round_as_delegate: {
         amount,
         game_id: gameId,
         player,
@@ -245,6 +416,99 @@ export class LifecycleClient extends LifecycleQueryClient implements LifecycleIn
       }
     }, fee, memo, _funds);
   };
+  revealRoundAsDelegate = async ({
+    gameId,
+    nonce,
+    player,
+    value
+  }: {
+    gameId: number;
+    nonce: number;
+    player: Addr;
+    value: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_round_as_delegate: {
+        game_id: gameId,
+        nonce,
+        player,
+        value
+      }
+    }, fee, memo, _funds);
+  };
+  playTurn = async ({
+    gameId,
+    value
+  }: {
+    gameId: number;
+    value: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      play_turn: {
+        game_id: gameId,
+        value
+      }
+    }, fee, memo, _funds);
+  };
+  grantDelegate = async ({
+    delegate,
+    expiresAt,
+    gameId,
+    maxActions
+  }: {
+    delegate: Addr;
+    expiresAt: Expiration;
+    gameId?: number;
+    maxActions: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      grant_delegate: {
+        delegate,
+        expires_at: expiresAt,
+        game_id: gameId,
+        max_actions: maxActions
+      }
+    }, fee, memo, _funds);
+  };
+  revokeDelegate = async ({
+    delegate
+  }: {
+    delegate: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_delegate: {
+        delegate
+      }
+    }, fee, memo, _funds);
+  };
+  relaySigned = async ({
+    payload,
+    pubkey,
+    signature
+  }: {
+    payload: RelayPayload;
+    pubkey: Binary;
+    signature: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      relay_signed: {
+        payload,
+        pubkey,
+        signature
+      }
+    }, fee, memo, _funds);
+  };
+  advanceRound = async ({
+    gameId
+  }: {
+    gameId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      advance_round: {
+        game_id: gameId
+      }
+    }, fee, memo, _funds);
+  };
   endGame = async ({
     gameId
   }: {
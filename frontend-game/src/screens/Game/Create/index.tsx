@@ -10,7 +10,8 @@ import { CwCooperationDilemmaClient } from "../../../../codegen/CwCooperationDil
 import { CONTRACTS, TREASURY } from "../../../constants/contracts";
 import { LifecycleClient } from "../../../../codegen/Lifecycle.client";
 import { SigningCosmWasmClient } from "@cosmjs/cosmwasm-stargate";
-import { ExecuteMsg, GameConfig } from "../../../../codegen/Lifecycle.types";
+import { GameConfigForDilemmaConfig } from "../../../../codegen/CwCooperationDilemma.types";
+import { GameConfigForAsteroidConfig } from "../../../../codegen/CwAsteroid.types";
 import { useQuery, useQueryClient } from "@tanstack/react-query";
 import WebApp from "@twa-dev/sdk";
 import { useNavigate } from "react-router-dom";
@@ -212,12 +213,14 @@ export const CreateGame = () => {
         return;
       }
 
-      const gameConfig: GameConfig = {
+      const gameConfig: GameConfigForDilemmaConfig = {
+        absence_policy: "forfeit",
         has_turns: true,
         max_rounds: 2,
         min_deposit: "0",
         min_players: 2,
         skip_reveal: false,
+        extension: { payoffs: null },
       };
 
       console.log("My address", account?.bech32Address);
@@ -334,12 +337,14 @@ export const CreateGame = () => {
         return;
       }
 
-      const gameConfig: GameConfig = {
+      const gameConfig: GameConfigForAsteroidConfig = {
+        absence_policy: "forfeit",
         has_turns: true,
         max_rounds: 1,
         min_deposit: "0",
         min_players: 2,
         skip_reveal: false,
+        extension: { handicaps: [] },
       };
 
       console.log("My address", account?.bech32Address);
@@ -604,7 +609,7 @@ export const CreateGame = () => {
                         <p className="text-gray-300">
                           Current Round:{" "}
                           <span className="text-white font-medium">
-                            {roundData?.current_round || "Not started"}
+                            {roundData?.round || "Not started"}
                           </span>
                         </p>
                       </div>
@@ -659,7 +664,7 @@ export const CreateGame = () => {
                     <p className="text-gray-300">
                       Current round:{" "}
                       <span className="text-white font-medium">
-                        {roundData?.current_round}
+                        {roundData?.round}
                       </span>
                     </p>
                   </div>
@@ -98,7 +98,7 @@ export const Rooms = () => {
                 id: index,
                 game_name: "Dilemma",
                 status: status || "unknown",
-                currentRound: round || undefined,
+                currentRound: round?.round,
               };
             } catch (gameError) {
               console.error(
@@ -124,7 +124,7 @@ export const Rooms = () => {
                 id: index,
                 game_name: "Asteroid",
                 status: status || "unknown",
-                currentRound: round || undefined,
+                currentRound: round?.round,
               };
             } catch (gameError) {
               console.error(
@@ -298,7 +298,7 @@ export const Rooms = () => {
                       </div>
                     )}
 
-                    {room.currentRound && (
+                    {room.currentRound !== undefined && (
                       <div className="bg-[#160f28]/50 rounded-lg p-3">
                         <p className="text-gray-300">
                           Round:{" "}
@@ -0,0 +1,183 @@
+//! Attribute macros merging the `cw-game-lifecycle` messages into a game's own message enums,
+//! so game specific variants can sit next to the lifecycle ones without copying them by hand.
+//!
+//! Both attributes must be placed above `#[cw_serde]` so the variants are added before the
+//! serde and schema derives run:
+//!
+//! ```ignore
+//! #[lifecycle_execute(AsteroidConfig)]
+//! #[cw_serde]
+//! pub enum ExecuteMsg {
+//!     // ... custom execute messages
+//! }
+//!
+//! #[lifecycle_query(AsteroidConfig)]
+//! #[cw_serde]
+//! #[derive(QueryResponses)]
+//! pub enum QueryMsg {
+//!     #[returns(AsteroidConfig)]
+//!     GetConfig {},
+//! }
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, parse_quote, Fields, ItemEnum, Token, Type, Variant};
+
+/// Parses the optional config extension type passed to the attribute
+fn parse_extension(attr: TokenStream) -> syn::Result<Option<Type>> {
+    if attr.is_empty() {
+        Ok(None)
+    } else {
+        syn::parse(attr).map(Some)
+    }
+}
+
+/// Adds every lifecycle query to the annotated `QueryMsg` with its `#[returns]` type and
+/// generates the conversions between it and `cw_game_lifecycle::msg::QueryMsg`. Queries that
+/// are not lifecycle queries fail `TryFrom`, so a contract handles its own variants first:
+///
+/// ```ignore
+/// match msg {
+///     QueryMsg::GetConfig {} => to_json_binary(&CONFIG.load(deps.storage)?),
+///     _ => Asteroid::query(deps, env, msg.try_into()?),
+/// }
+/// ```
+///
+/// The argument is the game's config extension, leave it out for games without one.
+#[proc_macro_attribute]
+pub fn lifecycle_query(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let extension = match parse_extension(attr) {
+        Ok(extension) => extension,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let item = parse_macro_input!(item as ItemEnum);
+
+    let game: Type = match extension {
+        Some(extension) => parse_quote!(::cw_game_lifecycle::state::Game<#extension>),
+        None => parse_quote!(::cw_game_lifecycle::state::Game),
+    };
+    // the variants are listed once, next to `cw_game_lifecycle::msg::QueryMsg`, and handed back
+    // to `merge_lifecycle_queries` along with the annotated enum
+    quote! {
+        ::cw_game_lifecycle::lifecycle_queries!(
+            ::cw_game_lifecycle::merge_lifecycle_queries { #item } #game
+        );
+    }
+    .into()
+}
+
+/// The annotated `QueryMsg` followed by the lifecycle query variants
+struct LifecycleQueries {
+    item: ItemEnum,
+    queries: Punctuated<Variant, Token![,]>,
+}
+
+impl Parse for LifecycleQueries {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(LifecycleQueries {
+            item: input.parse()?,
+            queries: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// Called back by `cw_game_lifecycle::lifecycle_queries!` from `#[lifecycle_query]`, adds the
+/// lifecycle queries to the game's `QueryMsg`
+#[doc(hidden)]
+#[proc_macro]
+pub fn merge_lifecycle_queries(input: TokenStream) -> TokenStream {
+    let LifecycleQueries { mut item, queries } = parse_macro_input!(input as LifecycleQueries);
+    item.variants.extend(queries.iter().cloned());
+
+    let conversions = match query_conversions(&item, &queries) {
+        Ok(conversions) => conversions,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    quote!(#item #conversions).into()
+}
+
+fn query_conversions(
+    item: &ItemEnum,
+    queries: &Punctuated<Variant, Token![,]>,
+) -> syn::Result<TokenStream2> {
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let arms = queries
+        .iter()
+        .map(|query| {
+            let name = &query.ident;
+            let Fields::Named(fields) = &query.fields else {
+                return Err(syn::Error::new_spanned(query, "lifecycle queries have named fields"));
+            };
+            let field_names: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+            Ok((
+                quote!(::cw_game_lifecycle::msg::QueryMsg::#name { #(#field_names),* }),
+                quote!(#ident::#name { #(#field_names),* }),
+            ))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let (lifecycle, game): (Vec<_>, Vec<_>) = arms.into_iter().unzip();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<::cw_game_lifecycle::msg::QueryMsg>
+            for #ident #ty_generics #where_clause
+        {
+            fn from(msg: ::cw_game_lifecycle::msg::QueryMsg) -> Self {
+                match msg {
+                    #(#lifecycle => #game,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#ident #ty_generics>
+            for ::cw_game_lifecycle::msg::QueryMsg #where_clause
+        {
+            type Error = ::cosmwasm_std::StdError;
+
+            // the fallback arm is unreachable for games without custom queries
+            #[allow(unreachable_patterns)]
+            fn try_from(msg: #ident #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                match msg {
+                    #(#game => ::core::result::Result::Ok(#lifecycle),)*
+                    _ => ::core::result::Result::Err(::cosmwasm_std::StdError::generic_err(
+                        "not a lifecycle query",
+                    )),
+                }
+            }
+        }
+    })
+}
+
+/// Adds the `Lifecycle` variant wrapping `cw_game_lifecycle::msg::ExecuteMsg` to the annotated
+/// `ExecuteMsg`, along with a `From` impl for it. The argument is the game's config extension,
+/// leave it out for games without one.
+#[proc_macro_attribute]
+pub fn lifecycle_execute(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let extension = match parse_extension(attr) {
+        Ok(extension) => extension,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let mut item = parse_macro_input!(item as ItemEnum);
+
+    let lifecycle: Type = match extension {
+        Some(extension) => parse_quote!(::cw_game_lifecycle::msg::ExecuteMsg<#extension>),
+        None => parse_quote!(::cw_game_lifecycle::msg::ExecuteMsg),
+    };
+    item.variants.push(parse_quote!(Lifecycle(#lifecycle)));
+
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    quote! {
+        #item
+
+        impl #impl_generics ::core::convert::From<#lifecycle> for #ident #ty_generics #where_clause {
+            fn from(msg: #lifecycle) -> Self {
+                #ident::Lifecycle(msg)
+            }
+        }
+    }
+    .into()
+}
@@ -1,6 +1,6 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
 // use cw2::set_contract_version;
 
 use crate::error::ContractError;
@@ -15,7 +15,9 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Base contract implementation which inherits the GameLifecycle trait and its default implementations
 pub struct BaseContract;
-impl GameLifecycle for BaseContract {}
+impl GameLifecycle for BaseContract {
+    type Extension = Empty;
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -18,6 +18,9 @@ pub enum ContractError {
     #[error("Game not in ready state and cannot be started")]
     GameNotInReadyState { game_id: u64 },
 
+    #[error("Game is not in progress")]
+    GameNotInProgress { game_id: u64 },
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -42,6 +45,18 @@ pub enum ContractError {
     #[error("Round has not been committed")]
     RoundNotCommitted { game_id: u64, round: u8 },
 
+    #[error("Round deadline has not passed yet")]
+    RoundNotExpired { game_id: u64, round: u8 },
+
+    #[error("Default move {value} is not a valid choice")]
+    InvalidDefaultMove { value: String },
+
+    #[error("Commit bonds cannot be used in games that skip the reveal or are played in turns")]
+    BondRequiresReveal {},
+
+    #[error("Invalid game config: {reason}")]
+    InvalidGameConfig { reason: String },
+
     #[error("Insufficient funds for deposit")]
     InsufficientFunds {
         expected: Uint128,
@@ -54,9 +69,48 @@ pub enum ContractError {
     #[error("Round reveal mismatch")]
     RoundRevealMismatch{game_id: u64, round: u8},
 
+    #[error("Game is played in turns, moves cannot be committed")]
+    TurnBasedGame { game_id: u64 },
+
+    #[error("Game is not played in turns")]
+    NotTurnBasedGame { game_id: u64 },
+
+    #[error("It is not the player's turn")]
+    NotPlayerTurn { game_id: u64, player: Addr },
+
+    #[error("Invalid turn")]
+    InvalidTurn { game_id: u64, round: u8 },
+
     #[error("Invalid reveal choice")]
     InvalidRevealChoice { game_id: u64, round: u8 },
 
     #[error("Round already revealed")]
     RoundAlreadyRevealed { game_id: u64, round: u8 },
+
+    #[error("Player has no telegram ID registered in the identity contract")]
+    TelegramIdNotRegistered { player: Addr },
+
+    #[error("A telegram ID attestation is required to join")]
+    AttestationRequired {},
+
+    #[error("Telegram ID attestation has expired")]
+    AttestationExpired {},
+
+    #[error("Invalid telegram ID attestation")]
+    InvalidAttestation {},
+
+    #[error("Delegation has expired")]
+    DelegationExpired { player: Addr, delegate: Addr },
+
+    #[error("Delegation has no actions left")]
+    DelegationExhausted { player: Addr, delegate: Addr },
+
+    #[error("Invalid relay signature")]
+    InvalidRelaySignature {},
+
+    #[error("Invalid relay nonce, expected {expected}")]
+    InvalidRelayNonce { expected: u64 },
+
+    #[error("Relay payload is for another contract or round")]
+    RelayPayloadMismatch {},
 }
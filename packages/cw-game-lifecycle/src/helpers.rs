@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, StdResult, Uint128, WasmMsg};
-use crate::{msg::ExecuteMsg, state::{Game, GameConfig, GameRound, GameRoundStatus, GameStatus}};
+use cosmwasm_std::{to_json_binary, to_json_vec, Addr, Api, BlockInfo, CanonicalAddr, CosmosMsg, StdResult, Uint128, WasmMsg};
+use crate::{msg::{ExecuteMsg, RatingResponse, RelayPayload}, state::{AbsencePolicy, Game, GameConfig, GameOutcome, GameRound, GameRoundStatus, GameStatus, PlayerRating, PlayerStats, RatingConfig, TelegramAttestation}};
+use ripemd::Ripemd160;
+use cw_utils::{Duration, Expiration};
+use sha2::{Digest, Sha256};
 
 /// CwTemplateContract is a wrapper around Addr that provides a lot of helpers
 /// for working with this.
@@ -25,8 +28,8 @@ impl CwTemplateContract {
     }
 }
 
-impl Game {
-    pub fn new(id: u64, config: GameConfig, creator: Addr) -> Self {
+impl<Ext> Game<Ext> {
+    pub fn new(id: u64, config: GameConfig<Ext>, creator: Addr) -> Self {
         Self {
             id,
             creator,
@@ -38,6 +41,59 @@ impl Game {
             total_escrow: Uint128::zero(),
             player_escrow: vec![],
             scores: HashMap::new(),
+            slashed_bonds: vec![],
+        }
+    }
+
+    /// Derives each player's outcome from the final scores: the highest scoring player(s) win,
+    /// everyone else loses, and the game is a draw for all players if every score is equal.
+    pub fn outcomes(&self) -> Vec<(Addr, GameOutcome)> {
+        let score_of = |player: &Addr| self.scores.get(player).copied().unwrap_or_default();
+        let best = self.players.iter().map(|p| score_of(&p.0)).max().unwrap_or_default();
+        let is_draw = self.players.iter().all(|p| score_of(&p.0) == best);
+
+        self.players
+            .iter()
+            .map(|(player, _)| {
+                let outcome = match (is_draw, score_of(player) == best) {
+                    (true, _) => GameOutcome::Draw,
+                    (false, true) => GameOutcome::Win,
+                    (false, false) => GameOutcome::Loss,
+                };
+                (player.clone(), outcome)
+            })
+            .collect()
+    }
+
+    /// Rounds that ended with a move from every player, i.e. not voided, forfeited or in progress
+    pub fn scored_rounds(&self) -> impl Iterator<Item = &GameRound> {
+        self.rounds
+            .iter()
+            .filter(|r| r.status == GameRoundStatus::Ended && r.forfeited_by.is_empty())
+    }
+}
+
+impl PlayerStats {
+    /// Records the result of a finished game in the player's stats
+    pub fn record(&mut self, outcome: &GameOutcome, reward: Uint128, block_height: u64) {
+        self.games_played += 1;
+        self.total_rewards += reward;
+        self.last_played_block = block_height;
+
+        match outcome {
+            GameOutcome::Win => {
+                self.wins += 1;
+                self.current_win_streak += 1;
+                self.best_win_streak = self.best_win_streak.max(self.current_win_streak);
+            }
+            GameOutcome::Loss => {
+                self.losses += 1;
+                self.current_win_streak = 0;
+            }
+            GameOutcome::Draw => {
+                self.draws += 1;
+                self.current_win_streak = 0;
+            }
         }
     }
 }
@@ -58,23 +114,39 @@ pub trait GameRewards {
     fn calculate_winners_and_rewards(&mut self) -> StdResult<(Self::Scores, Self::Rewards)>;
 }
 
-impl Default for GameConfig {
+impl<Ext: Default> Default for GameConfig<Ext> {
     fn default() -> Self {
         Self {
             game_joining_fee: None,
             min_deposit: Uint128::zero(),
             max_players: Some(5),
             min_players: 2,
-            round_expiry_duration: Some(100),
+            commit_duration: Some(Duration::Height(100)),
+            reveal_duration: Some(Duration::Height(100)),
             max_rounds: 3,
             round_reward_multiplier: None,
             has_turns: false,
             skip_reveal: false,
+            absence_policy: AbsencePolicy::Forfeit,
+            commit_bond: None,
+            bond_treasury: None,
+            extension: Ext::default(),
         }
     }
 }
 
-impl GameConfig {
+impl Default for RatingConfig {
+    fn default() -> Self {
+        Self {
+            initial_rating: 1200,
+            k_factor: 20,
+            provisional_k_factor: 40,
+            provisional_games: 10,
+        }
+    }
+}
+
+impl<Ext: Default> GameConfig<Ext> {
     pub fn default_with_join_fee(join_fee: Uint128) -> Self {
         let mut default = Self::default();
         default.game_joining_fee = Some(join_fee);
@@ -83,7 +155,102 @@ impl GameConfig {
 }
 
 impl GameRound {
-    pub fn new(id: u8, expires_at: Option<u64>) -> Self {
-        Self { id, expires_at, commits: vec![], reveals: vec![], status: GameRoundStatus::Pending }
+    pub fn new(id: u8, commit_expires_at: Option<Expiration>) -> Self {
+        Self {
+            id,
+            commit_expires_at,
+            reveal_expires_at: None,
+            commits: vec![],
+            reveals: vec![],
+            forfeited_by: vec![],
+            status: GameRoundStatus::Pending,
+        }
+    }
+
+    /// Ends the round, applying the absence policy to the players who did not reveal, which are
+    /// returned
+    pub fn close(&mut self, policy: &AbsencePolicy, players: &[Addr]) -> Vec<Addr> {
+        let absent: Vec<Addr> = players
+            .iter()
+            .filter(|p| !self.reveals.iter().any(|r| r.0 == **p))
+            .cloned()
+            .collect();
+
+        self.status = match policy {
+            _ if absent.is_empty() => GameRoundStatus::Ended,
+            AbsencePolicy::Forfeit if !self.reveals.is_empty() => {
+                self.forfeited_by = absent.clone();
+                GameRoundStatus::Ended
+            }
+            AbsencePolicy::DefaultMove { value } => {
+                self.reveals.extend(absent.iter().map(|p| (p.clone(), value.clone())));
+                GameRoundStatus::Ended
+            }
+            // nobody revealed, there is no one to forfeit the round to
+            _ => GameRoundStatus::Voided,
+        };
+        absent
+    }
+
+    pub fn is_commit_expired(&self, block: &BlockInfo) -> bool {
+        self.commit_expires_at.is_some_and(|e| e.is_expired(block))
+    }
+
+    pub fn is_reveal_expired(&self, block: &BlockInfo) -> bool {
+        self.reveal_expires_at.is_some_and(|e| e.is_expired(block))
+    }
+
+    /// Moves the round to the reveal phase, the reveal window starts at the current block
+    pub fn start_reveal(&mut self, reveal_duration: Option<Duration>, block: &BlockInfo) {
+        self.status = GameRoundStatus::Committed;
+        self.reveal_expires_at = reveal_duration.map(|d| d.after(block));
+    }
+
+    /// Applies the transitions due to the commit and reveal windows having closed
+    pub fn apply_deadlines(&mut self, reveal_duration: Option<Duration>, block: &BlockInfo) {
+        if self.status == GameRoundStatus::Pending && self.is_commit_expired(block) {
+            self.start_reveal(reveal_duration, block);
+        }
+        if self.status == GameRoundStatus::Committed && self.is_reveal_expired(block) {
+            self.status = GameRoundStatus::Revealed;
+        }
     }
 }
+
+impl RatingResponse {
+    pub fn new(config: &RatingConfig, rating: PlayerRating) -> Self {
+        Self {
+            provisional: config.is_provisional(&rating),
+            player: rating.player,
+            rating: rating.rating,
+            games_rated: rating.games_rated,
+        }
+    }
+}
+
+impl TelegramAttestation {
+    /// SHA-256 digest signed by the bot, over the length prefixed telegram ID, player and game
+    /// contract addresses followed by the big endian expiry timestamp
+    pub fn digest(telegram_id: &str, player: &Addr, contract: &Addr, expires_at: u64) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for field in [telegram_id.as_bytes(), player.as_bytes(), contract.as_bytes()] {
+            hasher.update((field.len() as u32).to_be_bytes());
+            hasher.update(field);
+        }
+        hasher.update(expires_at.to_be_bytes());
+        hasher.finalize().to_vec()
+    }
+}
+
+impl RelayPayload {
+    /// SHA-256 digest of the JSON encoded payload, signed by the player
+    pub fn digest(&self) -> StdResult<Vec<u8>> {
+        Ok(Sha256::digest(to_json_vec(self)?).to_vec())
+    }
+}
+
+/// Derives the chain address of a compressed secp256k1 public key, i.e. RIPEMD-160(SHA-256(pubkey))
+pub fn pubkey_to_address(api: &dyn Api, pubkey: &[u8]) -> StdResult<Addr> {
+    let hash = Ripemd160::digest(Sha256::digest(pubkey));
+    api.addr_humanize(&CanonicalAddr::from(hash.as_slice()))
+}
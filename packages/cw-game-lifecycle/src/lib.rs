@@ -4,5 +4,11 @@ pub mod helpers;
 pub mod msg;
 pub mod state;
 pub mod lifecycle;
+pub mod rating;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 pub use crate::error::ContractError;
+pub use cw_game_lifecycle_derive::{lifecycle_execute, lifecycle_query};
+#[doc(hidden)]
+pub use cw_game_lifecycle_derive::merge_lifecycle_queries;
@@ -1,21 +1,37 @@
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Debug;
 use std::ops::Add;
 
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult,
-    Uint128, WasmMsg,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, Event, MessageInfo, Order, Response,
+    StdResult, Storage, Uint128, WasmMsg,
 };
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
+use cw_identity::msg::QueryMsg as IdentityQueryMsg;
 use cw_p2e::msg::ExecuteMsg as P2EExecuteMsg;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::helpers::pubkey_to_address;
+use crate::msg::{
+    CurrentRoundResponse, ExecuteMsg, InstantiateMsg, QueryMsg, RatingResponse, RelayAction,
+    RelayPayload,
+};
 use crate::state::*;
 use hex;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Serialize};
 use sha2::{Digest, Sha256};
 
+const DEFAULT_RATINGS_LIMIT: u32 = 10;
+const MAX_RATINGS_LIMIT: u32 = 30;
+
 pub trait GameLifecycle {
+    /// Game specific parameters stored in `GameConfig::extension`, use `Empty` if there are none
+    type Extension: Serialize + DeserializeOwned + Clone + Debug + PartialEq + JsonSchema + Default;
+
     // Entry points
     fn instantiate(
         deps: DepsMut,
@@ -29,8 +45,11 @@ pub trait GameLifecycle {
                 base_url: msg.base_url,
                 image_url: msg.image_url,
                 token_contract: msg.token_contract,
+                identity_contract: msg.identity_contract,
+                attestation_pubkey: msg.attestation_pubkey,
             },
         )?;
+        RATING_CONFIG.save(deps.storage, &msg.rating_config.unwrap_or_default())?;
         OWNER.save(deps.storage, &info.sender)?;
         GAME_ID_COUNTER.save(deps.storage, &0)?;
         ADMINS.save(deps.storage, &vec![])?;
@@ -42,42 +61,77 @@ pub trait GameLifecycle {
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: ExecuteMsg,
+        msg: ExecuteMsg<Self::Extension>,
     ) -> Result<Response, ContractError> {
         match msg {
             ExecuteMsg::CreateGame { config } => Self::create_game(deps, info, config),
             ExecuteMsg::JoinGame {
                 game_id,
                 telegram_id,
-            } => Self::join_game(deps, env, info, game_id, telegram_id),
+                attestation,
+            } => Self::join_game(deps, env, info, game_id, telegram_id, attestation),
             ExecuteMsg::StartGame { game_id } => Self::start_game(deps, env, info, game_id),
             ExecuteMsg::CommitRound {
                 game_id,
                 value,
                 amount,
             } => Self::commit_round(deps, env, info, game_id, value, amount),
-            ExecuteMsg::CommitRoundAsAdmin {
+            ExecuteMsg::CommitRoundAsDelegate {
                 game_id,
                 value,
                 amount,
                 player,
-            } => Self::commit_round_as_admin(deps, env, info, game_id, player, value, amount),
+            } => Self::commit_round_as_delegate(deps, env, info, game_id, player, value, amount),
             ExecuteMsg::RevealRound {
                 game_id,
                 value,
                 nonce,
             } => Self::reveal_round(deps, env, info, game_id, value, nonce),
+            ExecuteMsg::RevealRoundAsDelegate {
+                game_id,
+                value,
+                nonce,
+                player,
+            } => Self::reveal_round_as_delegate(deps, env, info, game_id, player, value, nonce),
+            ExecuteMsg::PlayTurn { game_id, value } => {
+                Self::play_turn(deps, env, info, game_id, value)
+            }
+            ExecuteMsg::GrantDelegate {
+                delegate,
+                game_id,
+                expires_at,
+                max_actions,
+            } => Self::grant_delegate(deps, env, info, delegate, game_id, expires_at, max_actions),
+            ExecuteMsg::RevokeDelegate { delegate } => Self::revoke_delegate(deps, info, delegate),
+            ExecuteMsg::RelaySigned {
+                payload,
+                pubkey,
+                signature,
+            } => Self::relay_signed(deps, env, info, payload, pubkey, signature),
+            ExecuteMsg::AdvanceRound { game_id } => Self::advance_round(deps, env, game_id),
             ExecuteMsg::EndGame { game_id } => Self::end_game(deps, env, info, game_id),
         }
     }
 
-    fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
             QueryMsg::GetGame { game_id } => Self::get_game(deps, game_id),
             QueryMsg::GetLeaderboard {} => Self::get_leaderboard(deps),
-            QueryMsg::GetCurrentRound { game_id } => Self::get_current_round(deps, game_id),
+            QueryMsg::GetCurrentRound { game_id } => Self::get_current_round(deps, env, game_id),
             QueryMsg::GetGameStatus { game_id } => Self::get_game_status(deps, game_id),
             QueryMsg::GetGamesCount {} => Self::get_games_count(deps),
+            QueryMsg::GetPlayerStats { player } => Self::get_player_stats(deps, player),
+            QueryMsg::GetPlayerStatsByTelegramId { telegram_id } => {
+                Self::get_player_stats_by_telegram_id(deps, telegram_id)
+            }
+            QueryMsg::GetRating { player } => Self::get_rating(deps, player),
+            QueryMsg::GetDelegation { player, delegate } => {
+                Self::get_delegation(deps, player, delegate)
+            }
+            QueryMsg::GetRelayNonce { player } => Self::get_relay_nonce(deps, player),
+            QueryMsg::GetTopRatings { start_after, limit } => {
+                Self::get_top_ratings(deps, start_after, limit)
+            }
         }
     }
 
@@ -85,14 +139,29 @@ pub trait GameLifecycle {
     fn create_game(
         deps: DepsMut,
         info: MessageInfo,
-        config: GameConfig,
+        config: GameConfig<Self::Extension>,
     ) -> Result<Response, ContractError> {
         // Increment the game ID counter and use current value as the new game ID
         let game_id = GAME_ID_COUNTER.load(deps.storage)?;
         GAME_ID_COUNTER.save(deps.storage, &(game_id + 1))?;
 
+        if let AbsencePolicy::DefaultMove { value } = &config.absence_policy {
+            if !Self::is_valid_reveal_choice(value) {
+                return Err(ContractError::InvalidDefaultMove {
+                    value: value.clone(),
+                });
+            }
+        }
+
+        if config.commit_bond.is_some() && (config.skip_reveal || Self::is_turn_based(&config)) {
+            // bonds are only returned on reveal
+            return Err(ContractError::BondRequiresReveal {});
+        }
+
+        Self::validate_config(deps.as_ref(), &config)?;
+
         let game = Game::new(game_id, config, info.sender);
-        GAMES.save(deps.storage, game_id, &game)?;
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
 
         Ok(Response::new()
             .add_attribute("action", "create_game")
@@ -105,7 +174,7 @@ pub trait GameLifecycle {
         _info: MessageInfo,
         game_id: u64,
     ) -> Result<Response, ContractError> {
-        let mut game = GAMES.load(deps.storage, game_id)?;
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
 
         if game.status != GameStatus::Ready {
             return Err(ContractError::GameNotInReadyState { game_id });
@@ -114,13 +183,10 @@ pub trait GameLifecycle {
         game.status = GameStatus::InProgress;
         game.current_round = 1;
 
-        let round_expiry = match game.config.round_expiry_duration {
-            Some(block_duration) => Some(env.block.height + block_duration),
-            None => None,
-        };
-        game.rounds.push(GameRound::new(1, round_expiry));
+        let commit_expiry = game.config.commit_duration.map(|d| d.after(&env.block));
+        game.rounds.push(GameRound::new(1, commit_expiry));
 
-        GAMES.save(deps.storage, game_id, &game)?;
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
 
         Ok(Response::new()
             .add_attribute("action", "start_game")
@@ -133,8 +199,9 @@ pub trait GameLifecycle {
         info: MessageInfo,
         game_id: u64,
         telegram_id: String,
+        attestation: Option<TelegramAttestation>,
     ) -> Result<Response, ContractError> {
-        let mut game = GAMES.load(deps.storage, game_id)?;
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
 
         if game.status != GameStatus::Created {
             // game cannot be joined since it's either already started or ended
@@ -151,13 +218,15 @@ pub trait GameLifecycle {
         }
 
         // add the player to the game
+        let telegram_id = Self::resolve_telegram_id(deps.as_ref(), &info.sender, telegram_id)?;
+        Self::verify_attestation(deps.as_ref(), &env, &info.sender, &telegram_id, attestation)?;
         game.players.push((info.sender.clone(), telegram_id));
         // check if the game is ready to start and update the game status accordingly
         if game.players.len() >= game.config.min_players as usize {
             game.status = GameStatus::Ready;
         }
 
-        GAMES.save(deps.storage, game_id, &game)?;
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
 
         let mut response = Response::new()
             .add_attribute("action", "join_game")
@@ -180,13 +249,18 @@ pub trait GameLifecycle {
         value: String,
         amount: Option<Uint128>,
     ) -> Result<Response, ContractError> {
-        let mut game = GAMES.load(deps.storage, game_id)?;
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
 
-        if !game.players.iter().any(|p| p.0 == player) {
+        if Self::is_turn_based(&game.config) {
+            // moves are played in the open with `PlayTurn`
+            return Err(ContractError::TurnBasedGame { game_id });
+        } else if !game.players.iter().any(|p| p.0 == player) {
             // player is not in the game, throw an error
             return Err(ContractError::PlayerNotInGame { game_id, player });
         }
 
+        let player_count = game.players.len();
+        let reveal_duration = game.config.reveal_duration;
         let round = game
             .rounds
             .iter_mut()
@@ -201,24 +275,39 @@ pub trait GameLifecycle {
             return Err(ContractError::RoundAlreadyCommitted { game_id, player });
         }
 
-        // check block_expired < current_block and status
-        if env.block.height >= round.expires_at.unwrap_or(u64::MAX) {
-            // round has expired
+        // commits are only accepted while the commit window is open
+        if round.status != GameRoundStatus::Pending || round.is_commit_expired(&env.block) {
             return Err(ContractError::RoundExpired {
                 game_id,
                 round: round.id,
             });
         }
 
-        round.commits.push((player, value, amount));
+        round.commits.push((player.clone(), value, amount));
+
+        if round.commits.len() >= player_count {
+            // round is full, all players have committed and the reveal window opens
+            round.start_reveal(reveal_duration, &env.block);
+        }
 
-        if round.commits.len() >= game.players.len() {
-            // round is full, all players have committed
-            round.status = GameRoundStatus::Committed;
+        let mut response = Response::new();
+        if let Some(bond) = game.config.commit_bond {
+            // escrow the player's bond in the game contract until they reveal
+            let metadata = GAME_METADATA.load(deps.storage)?;
+            response = response.add_message(WasmMsg::Execute {
+                contract_addr: metadata.token_contract.to_string(),
+                msg: to_json_binary(&P2EExecuteMsg::TransferFrom {
+                    owner: player.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount: bond,
+                })?,
+                funds: vec![],
+            });
+            game.total_escrow += bond;
         }
 
-        GAMES.save(deps.storage, game_id, &game)?;
-        Ok(Response::new())
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
+        Ok(response)
     }
 
     fn commit_round(
@@ -232,7 +321,7 @@ pub trait GameLifecycle {
         Self::_commit_round(deps, env, game_id, info.sender, value, amount)
     }
 
-    fn commit_round_as_admin(
+    fn commit_round_as_delegate(
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
@@ -241,25 +330,20 @@ pub trait GameLifecycle {
         value: String,
         amount: Option<Uint128>,
     ) -> Result<Response, ContractError> {
-        if !ADMINS.load(deps.storage)?.contains(&info.sender)
-            && OWNER.load(deps.storage)? != info.sender
-        {
-            return Err(ContractError::Unauthorized {});
-        }
-
+        Self::use_delegation(deps.storage, &env, &player, &info.sender, game_id)?;
         Self::_commit_round(deps, env, game_id, player, value, amount)
     }
 
-    fn reveal_round(
+    fn _reveal_round(
         deps: DepsMut,
         env: Env,
-        info: MessageInfo,
         game_id: u64,
+        player: Addr,
         value: String,
         nonce: u64,
     ) -> Result<Response, ContractError> {
         let mut events: Vec<Event> = vec![];
-        let mut game = GAMES.load(deps.storage, game_id)?;
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
 
         if game.config.skip_reveal {
             // skip reveal, just calculate the winner
@@ -270,6 +354,8 @@ pub trait GameLifecycle {
                 .add_attribute("round_id", game.current_round.to_string()));
         }
 
+        let is_valid_move = Self::is_valid_move(deps.as_ref(), &game, &player, &value)?;
+        let reveal_duration = game.config.reveal_duration;
         let round = game
             .rounds
             .iter_mut()
@@ -278,21 +364,26 @@ pub trait GameLifecycle {
                 game_id,
                 round: game.current_round,
             })?;
+        round.apply_deadlines(reveal_duration, &env.block);
 
-        // Not all players committed and the round has not expired
-        if round.status != GameRoundStatus::Committed
-            && round.expires_at.unwrap_or(u64::MAX) > env.block.height
-        {
+        if round.status == GameRoundStatus::Pending {
+            // not all players committed and the commit window is still open
             return Err(ContractError::RoundNotCommitted {
                 game_id,
                 round: round.id,
             });
+        } else if round.status != GameRoundStatus::Committed {
+            // the reveal window has closed
+            return Err(ContractError::RoundExpired {
+                game_id,
+                round: round.id,
+            });
         }
 
         let player_commit = round
             .commits
             .iter()
-            .find(|c| c.0 == info.sender)
+            .find(|c| c.0 == player)
             .map(|c| c.1.clone());
 
         let mut hasher = Sha256::new();
@@ -306,7 +397,7 @@ pub trait GameLifecycle {
                 game_id: game_id,
                 round: game.current_round,
             });
-        } else if !Self::is_valid_reveal_choice(&value) {
+        } else if !is_valid_move {
             return Err(ContractError::InvalidRevealChoice {
                 game_id,
                 round: game.current_round,
@@ -317,7 +408,7 @@ pub trait GameLifecycle {
         let player_existing_reveal = round
             .reveals
             .iter()
-            .find(|r| r.0 == info.sender)
+            .find(|r| r.0 == player)
             .map(|r| r.1.clone());
 
         if player_existing_reveal.is_some() {
@@ -327,64 +418,456 @@ pub trait GameLifecycle {
             });
         }
 
-        // push the revealed value and optionally close the round
-        round.reveals.push((info.sender.clone(), value));
+        // push the revealed value and close the round once every committed player revealed
+        round.reveals.push((player.clone(), value));
+        let all_revealed = round.reveals.len() >= round.commits.len();
+        if all_revealed {
+            round.status = GameRoundStatus::Revealed;
+        }
 
-        // if all players revealed or the round has expired, close the round
-        if round.reveals.len().eq(&game.players.len())
-            || round.expires_at.unwrap_or(u64::MAX).lt(&env.block.height)
-        {
-            round.status = GameRoundStatus::Ended;
-
-            // if the current round is equal to the max rounds, set the game status to RoundsFinished
-            // otherwise prepare the next round
-            match game.current_round.cmp(&game.config.max_rounds) {
-                Ordering::Equal => {
-                    // all rounds are finished, set the game status to RoundsFinished
-                    game.status = GameStatus::RoundsFinished;
-                    events.push(
-                        Event::new("game_rounds_finished").add_attribute("game_id", game_id.to_string()),
-                    );
-                }
-                Ordering::Less => {
-                    game.current_round += 1;
-                    let round_expiry = match game.config.round_expiry_duration {
-                        Some(block_duration) => Some(env.block.height + block_duration),
-                        None => None,
-                    };
-                    game.rounds.push(GameRound::new(game.current_round, round_expiry));
-                }
-                _ => {}
-            }
+        let metadata = GAME_METADATA.load(deps.storage)?;
+        let mut msgs = vec![];
+        if let Some(bond) = game.config.commit_bond {
+            // the bond is returned as soon as the player reveals
+            msgs.push(Self::transfer_bond(&metadata, &player, bond)?);
+            game.total_escrow -= bond;
+        }
+        if all_revealed {
+            msgs.extend(Self::close_round(&mut game, &env, &metadata, &mut events)?);
         }
 
-        GAMES.save(deps.storage, game_id, &game)?;
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
 
         Ok(Response::new()
+            .add_messages(msgs)
             .add_events(events)
             .add_attribute("action", "reveal_round")
             .add_attribute("game_id", game_id.to_string())
             .add_attribute("round_id", game.current_round.to_string())
-            .add_attribute("player", info.sender.clone().to_string()))
+            .add_attribute("player", player.to_string()))
+    }
+
+    fn reveal_round(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        game_id: u64,
+        value: String,
+        nonce: u64,
+    ) -> Result<Response, ContractError> {
+        Self::_reveal_round(deps, env, game_id, info.sender, value, nonce)
+    }
+
+    fn reveal_round_as_delegate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        game_id: u64,
+        player: Addr,
+        value: String,
+        nonce: u64,
+    ) -> Result<Response, ContractError> {
+        Self::use_delegation(deps.storage, &env, &player, &info.sender, game_id)?;
+        Self::_reveal_round(deps, env, game_id, player, value, nonce)
+    }
+
+    fn play_turn(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        game_id: u64,
+        value: String,
+    ) -> Result<Response, ContractError> {
+        let mut events: Vec<Event> = vec![];
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
+        let player = info.sender;
+
+        if !Self::is_turn_based(&game.config) {
+            return Err(ContractError::NotTurnBasedGame { game_id });
+        } else if !game.players.iter().any(|p| p.0 == player) {
+            return Err(ContractError::PlayerNotInGame { game_id, player });
+        }
+
+        let turn_order = Self::turn_order(&game, game.current_round);
+        let round = game
+            .rounds
+            .iter_mut()
+            .find(|r| r.id == game.current_round)
+            .ok_or(ContractError::RoundNotFound {
+                game_id,
+                round: game.current_round,
+            })?;
+
+        // the whole round has to be played within its commit window
+        if round.status != GameRoundStatus::Pending || round.is_commit_expired(&env.block) {
+            return Err(ContractError::RoundExpired {
+                game_id,
+                round: round.id,
+            });
+        } else if turn_order.get(round.reveals.len()) != Some(&player) {
+            return Err(ContractError::NotPlayerTurn { game_id, player });
+        } else if !Self::is_valid_turn(&game.config, round, &value) {
+            return Err(ContractError::InvalidTurn {
+                game_id,
+                round: round.id,
+            });
+        }
+
+        // turns are recorded as reveals in the order they are played
+        round.reveals.push((player.clone(), value));
+        let mut msgs = vec![];
+        if round.reveals.len() >= turn_order.len() {
+            round.status = GameRoundStatus::Revealed;
+            let metadata = GAME_METADATA.load(deps.storage)?;
+            msgs = Self::close_round(&mut game, &env, &metadata, &mut events)?;
+        }
+
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
+
+        Ok(Response::new()
+            .add_messages(msgs)
+            .add_events(events)
+            .add_attribute("action", "play_turn")
+            .add_attribute("game_id", game_id.to_string())
+            .add_attribute("round_id", game.current_round.to_string())
+            .add_attribute("player", player.to_string()))
+    }
+
+    fn grant_delegate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        delegate: Addr,
+        game_id: Option<u64>,
+        expires_at: Expiration,
+        max_actions: u32,
+    ) -> Result<Response, ContractError> {
+        let delegate = deps.api.addr_validate(delegate.as_str())?;
+        if expires_at.is_expired(&env.block) {
+            return Err(ContractError::DelegationExpired {
+                player: info.sender,
+                delegate,
+            });
+        }
+
+        let delegation = Delegation {
+            game_id,
+            expires_at,
+            max_actions,
+            actions_used: 0,
+        };
+        DELEGATIONS.save(deps.storage, (&info.sender, &delegate), &delegation)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "grant_delegate")
+            .add_attribute("player", info.sender.to_string())
+            .add_attribute("delegate", delegate.to_string()))
+    }
+
+    fn revoke_delegate(
+        deps: DepsMut,
+        info: MessageInfo,
+        delegate: Addr,
+    ) -> Result<Response, ContractError> {
+        DELEGATIONS.remove(deps.storage, (&info.sender, &delegate));
+
+        Ok(Response::new()
+            .add_attribute("action", "revoke_delegate")
+            .add_attribute("player", info.sender.to_string())
+            .add_attribute("delegate", delegate.to_string()))
+    }
+
+    fn advance_round(deps: DepsMut, env: Env, game_id: u64) -> Result<Response, ContractError> {
+        let mut events: Vec<Event> = vec![];
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
+
+        if game.status != GameStatus::InProgress {
+            return Err(ContractError::GameNotInProgress { game_id });
+        }
+
+        let reveal_duration = game.config.reveal_duration;
+        let turn_based = Self::is_turn_based(&game.config);
+        let round = game
+            .rounds
+            .iter_mut()
+            .find(|r| r.id == game.current_round)
+            .ok_or(ContractError::RoundNotFound {
+                game_id,
+                round: game.current_round,
+            })?;
+
+        let previous_status = round.status.clone();
+        if turn_based
+            && round.status == GameRoundStatus::Pending
+            && round.is_commit_expired(&env.block)
+        {
+            // turns are played in the open, there is no reveal window to wait for
+            round.status = GameRoundStatus::Revealed;
+        }
+        round.apply_deadlines(reveal_duration, &env.block);
+        if round.status == previous_status {
+            // nothing to do until the deadline of the current phase has passed
+            return Err(ContractError::RoundNotExpired {
+                game_id,
+                round: round.id,
+            });
+        }
+        let mut msgs = vec![];
+        if round.status == GameRoundStatus::Revealed {
+            let metadata = GAME_METADATA.load(deps.storage)?;
+            msgs = Self::close_round(&mut game, &env, &metadata, &mut events)?;
+        }
+
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
+
+        Ok(Response::new()
+            .add_messages(msgs)
+            .add_events(events)
+            .add_attribute("action", "advance_round")
+            .add_attribute("game_id", game_id.to_string())
+            .add_attribute("round_id", game.current_round.to_string()))
+    }
+
+    /// Ends the current round once it has been revealed and prepares the next round, or marks the
+    /// game as finished if it was the last round or the game is decided. Returns the messages
+    /// paying out slashed bonds
+    fn close_round(
+        game: &mut Game<Self::Extension>,
+        env: &Env,
+        metadata: &GameMetadata,
+        events: &mut Vec<Event>,
+    ) -> Result<Vec<WasmMsg>, ContractError> {
+        let players: Vec<Addr> = game.players.iter().map(|p| p.0.clone()).collect();
+        let msgs = Self::slash_bonds(game, metadata, events)?;
+        if let Some(round) = game.rounds.iter_mut().find(|r| r.id == game.current_round) {
+            let absent = round.close(&game.config.absence_policy, &players);
+            if !absent.is_empty() {
+                events.push(
+                    Event::new("round_absence")
+                        .add_attribute("game_id", game.id.to_string())
+                        .add_attribute("round_id", round.id.to_string())
+                        .add_attribute("round_status", format!("{:?}", round.status))
+                        .add_attributes(absent.iter().map(|p| ("player", p.to_string()))),
+                );
+            }
+        }
+
+        match game.current_round.cmp(&game.config.max_rounds) {
+            Ordering::Equal => {
+                // all rounds are finished, set the game status to RoundsFinished
+                game.status = GameStatus::RoundsFinished;
+                events.push(
+                    Event::new("game_rounds_finished").add_attribute("game_id", game.id.to_string()),
+                );
+            }
+            Ordering::Less if Self::is_decided(game) => {
+                // the outcome can no longer change, the remaining rounds are not played
+                game.status = GameStatus::RoundsFinished;
+                events.push(
+                    Event::new("game_rounds_finished")
+                        .add_attribute("game_id", game.id.to_string())
+                        .add_attribute("decided_at_round", game.current_round.to_string()),
+                );
+            }
+            Ordering::Less => {
+                game.current_round += 1;
+                let commit_expiry = game.config.commit_duration.map(|d| d.after(&env.block));
+                game.rounds.push(GameRound::new(game.current_round, commit_expiry));
+            }
+            _ => {}
+        }
+        Ok(msgs)
+    }
+
+    /// Slashes the bonds of the players who committed to the current round but never revealed.
+    /// Returns the messages paying them out to the treasury or to the players who revealed
+    fn slash_bonds(
+        game: &mut Game<Self::Extension>,
+        metadata: &GameMetadata,
+        events: &mut Vec<Event>,
+    ) -> Result<Vec<WasmMsg>, ContractError> {
+        let mut msgs = vec![];
+        let Some(bond) = game.config.commit_bond else {
+            return Ok(msgs);
+        };
+        let Some(round) = game.rounds.iter().find(|r| r.id == game.current_round) else {
+            return Ok(msgs);
+        };
+
+        let revealed: Vec<Addr> = round.reveals.iter().map(|r| r.0.clone()).collect();
+        let slashed: Vec<Addr> = round
+            .commits
+            .iter()
+            .map(|c| c.0.clone())
+            .filter(|p| !revealed.contains(p))
+            .collect();
+        if slashed.is_empty() {
+            return Ok(msgs);
+        }
+
+        let total = bond * Uint128::from(slashed.len() as u128);
+        let recipients = match &game.config.bond_treasury {
+            Some(treasury) => vec![treasury.clone()],
+            None => revealed,
+        };
+        // if nobody revealed and there is no treasury, the bonds stay in the contract and remain
+        // part of the escrow
+        if !recipients.is_empty() {
+            let count = Uint128::from(recipients.len() as u128);
+            let share = total.multiply_ratio(1u128, count);
+            // the first recipient also receives what is left of the split, so all of it is paid
+            let remainder = total - share * count;
+            for (i, recipient) in recipients.iter().enumerate() {
+                let amount = if i == 0 { share + remainder } else { share };
+                msgs.push(Self::transfer_bond(metadata, recipient, amount)?);
+            }
+            game.total_escrow -= total;
+        }
+
+        for player in slashed {
+            events.push(
+                Event::new("bond_slashed")
+                    .add_attribute("game_id", game.id.to_string())
+                    .add_attribute("round_id", round.id.to_string())
+                    .add_attribute("player", player.to_string())
+                    .add_attribute("amount", bond.to_string())
+                    .add_attributes(recipients.iter().map(|r| ("recipient", r.to_string()))),
+            );
+            game.slashed_bonds.push((round.id, player, bond));
+        }
+        Ok(msgs)
+    }
+
+    /// Settles the bonds of a round that is still open when the game is ended early: players whose
+    /// reveal window has closed are slashed as if the round had been closed, the others get their
+    /// bond back
+    fn settle_open_bonds(
+        game: &mut Game<Self::Extension>,
+        env: &Env,
+        metadata: &GameMetadata,
+        events: &mut Vec<Event>,
+    ) -> Result<Vec<WasmMsg>, ContractError> {
+        let mut msgs = vec![];
+        let Some(bond) = game.config.commit_bond else {
+            return Ok(msgs);
+        };
+        let reveal_duration = game.config.reveal_duration;
+        let Some(round) = game.rounds.iter_mut().find(|r| r.id == game.current_round) else {
+            return Ok(msgs);
+        };
+        if matches!(round.status, GameRoundStatus::Ended | GameRoundStatus::Voided) {
+            return Ok(msgs);
+        }
+
+        round.apply_deadlines(reveal_duration, &env.block);
+        if round.status == GameRoundStatus::Revealed {
+            return Self::slash_bonds(game, metadata, events);
+        }
+
+        let revealed: Vec<Addr> = round.reveals.iter().map(|r| r.0.clone()).collect();
+        for (player, _, _) in round.commits.iter().filter(|c| !revealed.contains(&c.0)) {
+            msgs.push(Self::transfer_bond(metadata, player, bond)?);
+            game.total_escrow -= bond;
+            events.push(
+                Event::new("bond_refunded")
+                    .add_attribute("game_id", game.id.to_string())
+                    .add_attribute("round_id", round.id.to_string())
+                    .add_attribute("player", player.to_string())
+                    .add_attribute("amount", bond.to_string()),
+            );
+        }
+        Ok(msgs)
+    }
+
+    fn transfer_bond(
+        metadata: &GameMetadata,
+        recipient: &Addr,
+        amount: Uint128,
+    ) -> Result<WasmMsg, ContractError> {
+        Ok(WasmMsg::Execute {
+            contract_addr: metadata.token_contract.to_string(),
+            msg: to_json_binary(&P2EExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        })
+    }
+
+    fn relay_signed(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        payload: RelayPayload,
+        pubkey: Binary,
+        signature: Binary,
+    ) -> Result<Response, ContractError> {
+        if payload.contract != env.contract.address {
+            return Err(ContractError::RelayPayloadMismatch {});
+        }
+
+        // only compressed keys map to an address, see `pubkey_to_address`
+        if pubkey.len() != 33 {
+            return Err(ContractError::InvalidRelaySignature {});
+        }
+        match deps.api.secp256k1_verify(&payload.digest()?, &signature, &pubkey) {
+            Ok(true) => {}
+            _ => return Err(ContractError::InvalidRelaySignature {}),
+        }
+        let player = pubkey_to_address(deps.api, &pubkey)?;
+
+        // every payload can only be relayed once
+        let expected = RELAY_NONCES.may_load(deps.storage, &player)?.unwrap_or_default();
+        if payload.nonce != expected {
+            return Err(ContractError::InvalidRelayNonce { expected });
+        }
+        RELAY_NONCES.save(deps.storage, &player, &(expected + 1))?;
+
+        let game = games::<Self::Extension>().load(deps.storage, payload.game_id)?;
+        if game.current_round != payload.round {
+            return Err(ContractError::RelayPayloadMismatch {});
+        }
+
+        let response = match payload.action {
+            RelayAction::Commit { value, amount } => {
+                Self::_commit_round(deps, env, payload.game_id, player, value, amount)?
+            }
+            RelayAction::Reveal { value, nonce } => {
+                Self::_reveal_round(deps, env, payload.game_id, player, value, nonce)?
+            }
+        };
+        Ok(response.add_attribute("relayer", info.sender.to_string()))
     }
 
     fn end_game(
         deps: DepsMut,
-        _env: Env,
+        env: Env,
         info: MessageInfo,
         game_id: u64,
     ) -> Result<Response, ContractError> {
         let is_admin = ADMINS.load(deps.storage)?.contains(&info.sender)
             || OWNER.load(deps.storage)? == info.sender;
         let metadata = GAME_METADATA.load(deps.storage)?;
-        let mut game = GAMES.load(deps.storage, game_id)?;
+        let mut game = games::<Self::Extension>().load(deps.storage, game_id)?;
+
+        if game.status == GameStatus::Ended {
+            // rewards, stats and ratings have already been settled
+            return Err(ContractError::CannotCloseGame {
+                reason: String::from("Game already ended!"),
+            });
+        }
 
         // check if the game can be ended (must be in progress and max rounds, if set, is reached)
-        match (game.status, is_admin) {
+        let mut bond_events = vec![];
+        let bond_msgs = match (&game.status, is_admin) {
             // admin can end the game at any time or if rounds are finished
             (GameStatus::RoundsFinished, _) | (_, true) => {
+                // bonds of a round the admin cut short must not stay locked in the escrow
+                let msgs = Self::settle_open_bonds(&mut game, &env, &metadata, &mut bond_events)?;
                 game.status = GameStatus::Ended;
-                Self::calculate_rewards_and_winners(&mut game)?
+                Self::calculate_rewards_and_winners(deps.as_ref(), &mut game)?;
+                Self::award_forfeits(&mut game);
+                msgs
             }
             _ => {
                 return Err(ContractError::CannotCloseGame {
@@ -393,9 +876,18 @@ pub trait GameLifecycle {
             }
         };
 
-        GAMES.save(deps.storage, game_id, &game)?;
+        games::<Self::Extension>().save(deps.storage, game_id, &game)?;
+        // a game ended before any round was scored, e.g. one that never started, was not played
+        if game.scored_rounds().next().is_some() {
+            Self::update_player_stats(deps.storage, &env, &game)?;
+            Self::update_ratings(deps.storage, &game)?;
+        }
+        let game_events = Self::on_game_ended(deps, &env, &game)?;
 
         let mut response = Response::new()
+            .add_messages(bond_msgs)
+            .add_events(bond_events)
+            .add_events(game_events)
             .add_attribute("action", "end_game")
             .add_attribute("game_id", game_id.to_string());
 
@@ -418,9 +910,28 @@ pub trait GameLifecycle {
     }
 
     // Queries
-    fn get_current_round(deps: Deps, game_id: u64) -> StdResult<Binary> {
-        let game = GAMES.load(deps.storage, game_id)?;
-        to_json_binary(&game.current_round)
+    fn get_current_round(deps: Deps, env: Env, game_id: u64) -> StdResult<Binary> {
+        let game = games::<Self::Extension>().load(deps.storage, game_id)?;
+        let round = game.rounds.iter().find(|r| r.id == game.current_round);
+
+        // the deadline of the phase the round is in
+        let expires_at = round.and_then(|r| match r.status {
+            GameRoundStatus::Pending => r.commit_expires_at,
+            GameRoundStatus::Committed => r.reveal_expires_at,
+            _ => None,
+        });
+
+        to_json_binary(&CurrentRoundResponse {
+            round: game.current_round,
+            status: round.map(|r| r.status.clone()),
+            expires_at,
+            // only time based expirations can be counted down to
+            expires_at_time: match expires_at {
+                Some(Expiration::AtTime(time)) => Some(time),
+                _ => None,
+            },
+            now: env.block.time,
+        })
     }
 
     fn get_leaderboard(deps: Deps) -> StdResult<Binary> {
@@ -429,12 +940,12 @@ pub trait GameLifecycle {
     }
 
     fn get_game(deps: Deps, game_id: u64) -> StdResult<Binary> {
-        let game = GAMES.load(deps.storage, game_id)?;
+        let game = games::<Self::Extension>().load(deps.storage, game_id)?;
         to_json_binary(&game)
     }
 
     fn get_game_status(deps: Deps, game_id: u64) -> StdResult<Binary> {
-        let game = GAMES.load(deps.storage, game_id)?;
+        let game = games::<Self::Extension>().load(deps.storage, game_id)?;
         to_json_binary(&game.status)
     }
 
@@ -443,12 +954,220 @@ pub trait GameLifecycle {
         to_json_binary(&games_count)
     }
 
+    fn get_player_stats(deps: Deps, player: Addr) -> StdResult<Binary> {
+        let stats = PLAYER_STATS.may_load(deps.storage, &player)?.unwrap_or_default();
+        to_json_binary(&stats)
+    }
+
+    fn get_player_stats_by_telegram_id(deps: Deps, telegram_id: String) -> StdResult<Binary> {
+        let player = TELEGRAM_PLAYERS.load(deps.storage, telegram_id)?;
+        Self::get_player_stats(deps, player)
+    }
+
+    fn get_delegation(deps: Deps, player: Addr, delegate: Addr) -> StdResult<Binary> {
+        let delegation = DELEGATIONS.may_load(deps.storage, (&player, &delegate))?;
+        to_json_binary(&delegation)
+    }
+
+    fn get_relay_nonce(deps: Deps, player: Addr) -> StdResult<Binary> {
+        let nonce = RELAY_NONCES.may_load(deps.storage, &player)?.unwrap_or_default();
+        to_json_binary(&nonce)
+    }
+
+    fn get_rating(deps: Deps, player: Addr) -> StdResult<Binary> {
+        let config = RATING_CONFIG.load(deps.storage)?;
+        let rating = ratings()
+            .may_load(deps.storage, &player)?
+            .unwrap_or_else(|| config.initial_rating(player));
+        to_json_binary(&RatingResponse::new(&config, rating))
+    }
+
+    fn get_top_ratings(deps: Deps, start_after: Option<Addr>, limit: Option<u32>) -> StdResult<Binary> {
+        let config = RATING_CONFIG.load(deps.storage)?;
+        let limit = limit.unwrap_or(DEFAULT_RATINGS_LIMIT).min(MAX_RATINGS_LIMIT) as usize;
+
+        // ratings are iterated from the highest, so continue below the last returned player
+        let start_after = match start_after.as_ref() {
+            Some(player) => Some(ratings().load(deps.storage, player)?),
+            None => None,
+        };
+        let max = start_after
+            .as_ref()
+            .map(|r| Bound::exclusive((r.rating.atomics().u128(), &r.player)));
+
+        let top_ratings = ratings()
+            .idx
+            .rating
+            .range(deps.storage, None, max, Order::Descending)
+            .take(limit)
+            .map(|item| item.map(|(_, rating)| RatingResponse::new(&config, rating)))
+            .collect::<StdResult<Vec<_>>>()?;
+        to_json_binary(&top_ratings)
+    }
+
     // Helpers
     fn is_valid_reveal_choice(_value: &String) -> bool {
         // Each game must implement its own logic to validate the reveal choice
         true
     }
 
+    fn is_valid_move(
+        _deps: Deps,
+        _game: &Game<Self::Extension>,
+        _player: &Addr,
+        value: &String,
+    ) -> StdResult<bool> {
+        // Games whose valid moves depend on contract settings or the player's role in the game
+        // can check them here, most games only need `is_valid_reveal_choice`
+        Ok(Self::is_valid_reveal_choice(value))
+    }
+
+    fn is_turn_based(_config: &GameConfig<Self::Extension>) -> bool {
+        // Games played in sequential turns with `PlayTurn` rather than simultaneous commits and
+        // reveals, every player then plays once per round in the order of `turn_order`. Games can
+        // offer both by deciding from the game's config
+        false
+    }
+
+    /// Order in which the players take their turns in a round of a turn based game. Players play
+    /// in the order they joined, starting with a different player every round.
+    fn turn_order(game: &Game<Self::Extension>, round: u8) -> Vec<Addr> {
+        let mut players: Vec<Addr> = game.players.iter().map(|p| p.0.clone()).collect();
+        if !players.is_empty() {
+            let first = (round as usize).saturating_sub(1) % players.len();
+            players.rotate_left(first);
+        }
+        players
+    }
+
+    fn is_valid_turn(
+        _config: &GameConfig<Self::Extension>,
+        _round: &GameRound,
+        value: &String,
+    ) -> bool {
+        // Games can validate a turn against their config and the turns already played in the
+        // round, which are its reveals
+        Self::is_valid_reveal_choice(value)
+    }
+
+    fn validate_config(
+        _deps: Deps,
+        _config: &GameConfig<Self::Extension>,
+    ) -> Result<(), ContractError> {
+        // Each game can validate its extension and the parts of the config it depends on
+        Ok(())
+    }
+
+    fn is_decided(_game: &Game<Self::Extension>) -> bool {
+        // Checked at the end of every round, games whose winner is known before the last round,
+        // e.g. a best of N match, can finish early
+        false
+    }
+
+    fn forfeit_reward(_config: &GameConfig<Self::Extension>, _round: u8) -> Uint128 {
+        // Reward for winning a round the other players forfeited by not revealing, games whose
+        // payoffs are not on this scale should override it
+        Uint128::new(100)
+    }
+
+    /// Adds the forfeit reward to the score of every player who revealed in a forfeited round
+    fn award_forfeits(game: &mut Game<Self::Extension>) {
+        game.rounds
+            .iter()
+            .filter(|r| r.status == GameRoundStatus::Ended && !r.forfeited_by.is_empty())
+            .flat_map(|r| {
+                let reward = Self::forfeit_reward(&game.config, r.id);
+                r.reveals.iter().map(move |(player, _)| (player, reward))
+            })
+            .for_each(|(player, reward)| {
+                *game.scores.entry(player.clone()).or_default() += reward;
+            });
+    }
+
+    fn use_delegation(
+        storage: &mut dyn Storage,
+        env: &Env,
+        player: &Addr,
+        delegate: &Addr,
+        game_id: u64,
+    ) -> Result<(), ContractError> {
+        let mut delegation = DELEGATIONS
+            .may_load(storage, (player, delegate))?
+            .ok_or(ContractError::Unauthorized {})?;
+
+        if delegation.expires_at.is_expired(&env.block) {
+            return Err(ContractError::DelegationExpired {
+                player: player.clone(),
+                delegate: delegate.clone(),
+            });
+        } else if delegation.game_id.is_some_and(|id| id != game_id) {
+            return Err(ContractError::Unauthorized {});
+        } else if delegation.actions_used >= delegation.max_actions {
+            return Err(ContractError::DelegationExhausted {
+                player: player.clone(),
+                delegate: delegate.clone(),
+            });
+        }
+
+        delegation.actions_used += 1;
+        DELEGATIONS.save(storage, (player, delegate), &delegation)?;
+        Ok(())
+    }
+
+    fn resolve_telegram_id(
+        deps: Deps,
+        player: &Addr,
+        telegram_id: String,
+    ) -> Result<String, ContractError> {
+        let metadata = GAME_METADATA.load(deps.storage)?;
+
+        // without an identity registry, the telegram ID sent by the player is trusted
+        let Some(identity_contract) = metadata.identity_contract else {
+            return Ok(telegram_id);
+        };
+
+        let registered_id: Option<String> = deps.querier.query_wasm_smart(
+            identity_contract,
+            &IdentityQueryMsg::GetTelegramId {
+                address: player.clone(),
+            },
+        )?;
+        registered_id.ok_or(ContractError::TelegramIdNotRegistered {
+            player: player.clone(),
+        })
+    }
+
+    fn verify_attestation(
+        deps: Deps,
+        env: &Env,
+        player: &Addr,
+        telegram_id: &str,
+        attestation: Option<TelegramAttestation>,
+    ) -> Result<(), ContractError> {
+        let metadata = GAME_METADATA.load(deps.storage)?;
+
+        // attestations are only checked if the contract has been configured with the bot's key
+        let Some(pubkey) = metadata.attestation_pubkey else {
+            return Ok(());
+        };
+        let attestation = attestation.ok_or(ContractError::AttestationRequired {})?;
+
+        if env.block.time.seconds() >= attestation.expires_at {
+            return Err(ContractError::AttestationExpired {});
+        }
+
+        let digest = TelegramAttestation::digest(
+            telegram_id,
+            player,
+            &env.contract.address,
+            attestation.expires_at,
+        );
+        match deps.api.secp256k1_verify(&digest, &attestation.signature, &pubkey) {
+            Ok(true) => Ok(()),
+            _ => Err(ContractError::InvalidAttestation {}),
+        }
+    }
+
     fn distribute_rewards(
         metadata: &GameMetadata,
         scores: &HashMap<Addr, Uint128>,
@@ -468,11 +1187,58 @@ pub trait GameLifecycle {
         Ok(msgs)
     }
 
+    fn update_player_stats(
+        storage: &mut dyn Storage,
+        env: &Env,
+        game: &Game<Self::Extension>,
+    ) -> Result<(), ContractError> {
+        for (player, outcome) in game.outcomes() {
+            let reward = game.scores.get(&player).copied().unwrap_or_default();
+            let mut stats = PLAYER_STATS.may_load(storage, &player)?.unwrap_or_default();
+            stats.record(&outcome, reward, env.block.height);
+            PLAYER_STATS.save(storage, &player, &stats)?;
+        }
+
+        // keep track of the player behind each telegram ID for stats lookups
+        for (player, telegram_id) in game.players.iter() {
+            TELEGRAM_PLAYERS.save(storage, telegram_id.clone(), player)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_ratings(storage: &mut dyn Storage, game: &Game<Self::Extension>) -> Result<(), ContractError> {
+        // Elo ratings are only defined for head to head games
+        if game.players.len() != 2 {
+            return Ok(());
+        }
+
+        let config = RATING_CONFIG.load(storage)?;
+        let outcomes = game.outcomes();
+        let current_ratings = outcomes
+            .iter()
+            .map(|(player, _)| {
+                ratings()
+                    .may_load(storage, player)
+                    .map(|r| r.unwrap_or_else(|| config.initial_rating(player.clone())))
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (i, (player, outcome)) in outcomes.iter().enumerate() {
+            let opponent_rating = current_ratings[1 - i].rating;
+            let mut rating = current_ratings[i].clone();
+            rating.record(&config, opponent_rating, outcome);
+            ratings().save(storage, player, &rating)?;
+        }
+
+        Ok(())
+    }
+
     fn process_joining_fee(
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        game: &mut Game,
+        game: &mut Game<Self::Extension>,
     ) -> Result<Option<WasmMsg>, ContractError> {
         let metadata = GAME_METADATA.load(deps.storage)?;
 
@@ -501,7 +1267,7 @@ pub trait GameLifecycle {
         _deps: DepsMut,
         _env: Env,
         _info: MessageInfo,
-        _game: &Game,
+        _game: &Game<Self::Extension>,
         _round_id: u64,
         _player: Addr,
     ) -> Result<bool, ContractError> {
@@ -509,8 +1275,21 @@ pub trait GameLifecycle {
         Ok(true)
     }
 
-    fn calculate_rewards_and_winners(_game: &mut Game) -> Result<bool, ContractError> {
-        // Each game must implement its own logic to calculate the rewards and winners
+    fn on_game_ended(
+        _deps: DepsMut,
+        _env: &Env,
+        _game: &Game<Self::Extension>,
+    ) -> Result<Vec<Event>, ContractError> {
+        // Games can keep their own records of finished games here, e.g. a high score table
+        Ok(vec![])
+    }
+
+    fn calculate_rewards_and_winners(
+        _deps: Deps,
+        _game: &mut Game<Self::Extension>,
+    ) -> Result<bool, ContractError> {
+        // Each game must implement its own logic to calculate the rewards and winners, reading
+        // any contract level settings it needs from storage
         Ok(true)
     }
 }
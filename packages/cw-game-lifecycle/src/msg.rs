@@ -1,59 +1,160 @@
-use crate::state::{Game, GameConfig, GameRound, GameStatus};
+use crate::state::{Game, GameConfig, GameRoundStatus, RatingConfig, TelegramAttestation};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Binary, Decimal, Empty, Timestamp, Uint128};
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub base_url: String,
     pub image_url: String,
     pub token_contract: Addr,
+    pub rating_config: Option<RatingConfig>, // defaults to `RatingConfig::default()`
+    pub identity_contract: Option<Addr>,
+    pub attestation_pubkey: Option<Binary>,
 }
 
 #[cw_serde]
-pub enum ExecuteMsg {
+pub enum ExecuteMsg<Ext = Empty> {
     CreateGame {
-        config: GameConfig,
+        config: GameConfig<Ext>,
     },
     StartGame {
         game_id: u64,
     },
     JoinGame {
         game_id: u64,
-        telegram_id: String,
+        telegram_id: String, // ignored in favour of the identity registry if one is configured
+        attestation: Option<TelegramAttestation>, // required if an attestation pubkey is configured
     },
     CommitRound {
         game_id: u64,
         value: String, // hashed value (choice, nonce)
         amount: Option<Uint128>,
     },
-    CommitRoundAsAdmin {
+    CommitRoundAsDelegate {
         game_id: u64,
         value: String,
         amount: Option<Uint128>,
-        player: Addr,
+        player: Addr, // player who granted the sender a delegation
     },
     RevealRound {
         game_id: u64,
         value: String, // revealed value
         nonce: u64,
     },
+    RevealRoundAsDelegate {
+        game_id: u64,
+        value: String,
+        nonce: u64,
+        player: Addr, // player who granted the sender a delegation
+    },
+    PlayTurn {
+        game_id: u64,
+        value: String, // played in the open, see `GameLifecycle::is_turn_based`
+    },
+    GrantDelegate {
+        delegate: Addr,
+        game_id: Option<u64>, // limits the delegation to a single game, any game if not set
+        expires_at: Expiration,
+        max_actions: u32, // number of commits and reveals the delegate can make
+    },
+    RevokeDelegate {
+        delegate: Addr,
+    },
+    RelaySigned {
+        payload: RelayPayload,
+        pubkey: Binary, // compressed secp256k1 public key of the player
+        signature: Binary, // signature over the SHA-256 digest of the JSON encoded payload
+    },
+    AdvanceRound {
+        game_id: u64, // closes the current phase of the round once its deadline has passed
+    },
     EndGame {
         game_id: u64, // Distribute rewards and update the leaderboard
     },
 }
 
 #[cw_serde]
-#[derive(QueryResponses)]
-pub enum QueryMsg {
-    #[returns(Game)]
-    GetGame { game_id: u64 },
-    #[returns(Vec<(Addr, String, Uint128)>)] // vector of (player addr, telegram ID, score)
-    GetLeaderboard {},
-    #[returns(GameRound)]
-    GetCurrentRound { game_id: u64 },
-    #[returns(GameStatus)]
-    GetGameStatus { game_id: u64 },
-    #[returns(u64)]
-    GetGamesCount {},
+pub enum RelayAction {
+    Commit { value: String, amount: Option<Uint128> },
+    Reveal { value: String, nonce: u64 },
+}
+
+/// A commit or reveal signed by the player and submitted on their behalf by a relayer
+#[cw_serde]
+pub struct RelayPayload {
+    pub contract: Addr,
+    pub game_id: u64,
+    pub round: u8,
+    pub action: RelayAction,
+    pub nonce: u64, // must match the player's relay nonce, see `QueryMsg::GetRelayNonce`
+}
+
+/// Passes the lifecycle queries, with their `#[returns]` types, to the `$callback` macro after
+/// its own arguments. `$game` is the type returned by `GetGame`. This is the only list of the
+/// lifecycle queries: `QueryMsg` below and the variants `#[lifecycle_query]` merges into a game's
+/// `QueryMsg` are both generated from it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! lifecycle_queries {
+    ($callback:path { $($args:tt)* } $game:ty) => {
+        $callback! {
+            $($args)*
+            #[returns($game)]
+            GetGame { game_id: u64 },
+            #[returns(Vec<(::cosmwasm_std::Addr, String, ::cosmwasm_std::Uint128)>)] // vector of (player addr, telegram ID, score)
+            GetLeaderboard {},
+            #[returns($crate::msg::CurrentRoundResponse)]
+            GetCurrentRound { game_id: u64 },
+            #[returns($crate::state::GameStatus)]
+            GetGameStatus { game_id: u64 },
+            #[returns(u64)]
+            GetGamesCount {},
+            #[returns($crate::state::PlayerStats)]
+            GetPlayerStats { player: ::cosmwasm_std::Addr },
+            #[returns($crate::state::PlayerStats)]
+            GetPlayerStatsByTelegramId { telegram_id: String },
+            #[returns(Option<$crate::state::Delegation>)]
+            GetDelegation { player: ::cosmwasm_std::Addr, delegate: ::cosmwasm_std::Addr },
+            #[returns(u64)]
+            GetRelayNonce { player: ::cosmwasm_std::Addr },
+            #[returns($crate::msg::RatingResponse)]
+            GetRating { player: ::cosmwasm_std::Addr },
+            #[returns(Vec<$crate::msg::RatingResponse>)] // sorted by rating, highest first
+            GetTopRatings {
+                start_after: Option<::cosmwasm_std::Addr>,
+                limit: Option<u32>,
+            },
+        }
+    };
+}
+
+macro_rules! query_msg {
+    ($($variants:tt)*) => {
+        #[cw_serde]
+        #[derive(QueryResponses)]
+        pub enum QueryMsg {
+            $($variants)*
+        }
+    };
+}
+
+lifecycle_queries!(query_msg {} Game);
+
+#[cw_serde]
+pub struct CurrentRoundResponse {
+    pub round: u8,
+    pub status: Option<GameRoundStatus>,
+    pub expires_at: Option<Expiration>, // deadline of the current commit or reveal phase
+    pub expires_at_time: Option<Timestamp>, // deadline to count down to, only set for time based expiry
+    pub now: Timestamp, // block time the response was computed at
+}
+
+#[cw_serde]
+pub struct RatingResponse {
+    pub player: Addr,
+    pub rating: Decimal,
+    pub games_rated: u64,
+    pub provisional: bool,
 }
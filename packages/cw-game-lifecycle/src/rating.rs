@@ -0,0 +1,110 @@
+use cosmwasm_std::{Addr, Decimal};
+
+use crate::state::{GameOutcome, PlayerRating, RatingConfig};
+
+/// Rating differences above this are capped, as in the FIDE rating tables
+const MAX_RATING_DIFFERENCE: u64 = 800;
+
+/// 10^(2^i / 400) as 18 decimal fixed-point values, used to compute 10^(d / 400) by multiplying
+/// together the factors for each bit set in the rating difference `d`
+const POW10_BY_BIT: [u128; 10] = [
+    1_005_773_063_001_738_243,
+    1_011_579_454_259_898_524,
+    1_023_292_992_280_754_131,
+    1_047_128_548_050_899_533,
+    1_096_478_196_143_185_013,
+    1_202_264_434_617_412_906,
+    1_445_439_770_745_927_512,
+    2_089_296_130_854_039_483,
+    4_365_158_322_401_659_675,
+    19_054_607_179_632_471_827,
+];
+
+/// Computes 10^(difference / 400) for a whole number rating difference
+fn pow10_over_400(difference: u64) -> Decimal {
+    let difference = difference.min(MAX_RATING_DIFFERENCE);
+    POW10_BY_BIT
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| difference & (1 << bit) != 0)
+        .fold(Decimal::one(), |acc, (_, factor)| acc * Decimal::raw(*factor))
+}
+
+/// Expected score of a player rated `rating` against an opponent rated `opponent_rating`,
+/// i.e. 1 / (1 + 10^((opponent_rating - rating) / 400))
+pub fn expected_score(rating: Decimal, opponent_rating: Decimal) -> Decimal {
+    if opponent_rating >= rating {
+        let difference = (opponent_rating - rating).to_uint_floor().u128() as u64;
+        Decimal::one() / (Decimal::one() + pow10_over_400(difference))
+    } else {
+        let difference = (rating - opponent_rating).to_uint_floor().u128() as u64;
+        Decimal::one() - Decimal::one() / (Decimal::one() + pow10_over_400(difference))
+    }
+}
+
+impl GameOutcome {
+    /// Score of the outcome as used by the Elo formula
+    pub fn elo_score(&self) -> Decimal {
+        match self {
+            GameOutcome::Win => Decimal::one(),
+            GameOutcome::Draw => Decimal::percent(50),
+            GameOutcome::Loss => Decimal::zero(),
+        }
+    }
+}
+
+impl RatingConfig {
+    pub fn initial_rating(&self, player: Addr) -> PlayerRating {
+        PlayerRating {
+            player,
+            rating: Decimal::from_ratio(self.initial_rating, 1u64),
+            games_rated: 0,
+        }
+    }
+
+    pub fn is_provisional(&self, rating: &PlayerRating) -> bool {
+        rating.games_rated < self.provisional_games
+    }
+
+    /// K-factor applied to the player's next rating update
+    pub fn k_factor(&self, rating: &PlayerRating) -> Decimal {
+        if self.is_provisional(rating) {
+            Decimal::from_ratio(self.provisional_k_factor, 1u64)
+        } else {
+            Decimal::from_ratio(self.k_factor, 1u64)
+        }
+    }
+}
+
+impl PlayerRating {
+    /// Applies the Elo update for a game played against an opponent with the given rating
+    pub fn record(&mut self, config: &RatingConfig, opponent_rating: Decimal, outcome: &GameOutcome) {
+        let k_factor = config.k_factor(self);
+        let expected = expected_score(self.rating, opponent_rating);
+        let actual = outcome.elo_score();
+
+        self.rating = if actual >= expected {
+            self.rating + k_factor * (actual - expected)
+        } else {
+            self.rating.saturating_sub(k_factor * (expected - actual))
+        };
+        self.games_rated += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_score_is_symmetric() {
+        let a = Decimal::from_ratio(1400u64, 1u64);
+        let b = Decimal::from_ratio(1200u64, 1u64);
+
+        assert_eq!(expected_score(a, a), Decimal::percent(50));
+        assert_eq!(expected_score(a, b) + expected_score(b, a), Decimal::one());
+        // a 200 point advantage is expected to score ~0.76
+        assert!(expected_score(a, b) > Decimal::percent(75));
+        assert!(expected_score(a, b) < Decimal::percent(77));
+    }
+}
@@ -1,53 +1,115 @@
 use std::collections::HashMap;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Binary, Decimal, Empty, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::{Duration, Expiration};
+use serde::{Deserialize, Deserializer};
 
 pub const OWNER: Item<Addr> = Item::new("owner");
 pub const NAME: Item<String> = Item::new("name");
 pub const ADMINS: Item<Vec<Addr>> = Item::new("admins");
 pub const GAME_ID_COUNTER: Item<u64> = Item::new("game_id_counter");
 pub const GAME_METADATA: Item<GameMetadata> = Item::new("game_metadata");
-pub const GAMES: Map<u64, Game> = Map::new("games"); // (Game ID, Game)
+pub const RATING_CONFIG: Item<RatingConfig> = Item::new("rating_config");
 pub const LEADERBOARD: Map<String, (Addr, Uint128)> = Map::new("leaderboard"); // (Telegram_id, (address, total_rewards_achieved))
+pub const PLAYER_STATS: Map<&Addr, PlayerStats> = Map::new("player_stats"); // (player, stats across all games)
+pub const TELEGRAM_PLAYERS: Map<String, Addr> = Map::new("telegram_players"); // (Telegram_id, player)
+pub const DELEGATIONS: Map<(&Addr, &Addr), Delegation> = Map::new("delegations"); // ((player, delegate), delegation)
+pub const RELAY_NONCES: Map<&Addr, u64> = Map::new("relay_nonces"); // (player, next nonce of a signed payload)
+
+/// (Game ID, Game), `Ext` being the extension type of the game contract
+pub const fn games<Ext>() -> Map<u64, Game<Ext>> {
+    Map::new("games")
+}
+
+pub struct RatingIndexes<'a> {
+    pub rating: MultiIndex<'a, u128, PlayerRating, &'a Addr>,
+}
+
+impl IndexList<PlayerRating> for RatingIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PlayerRating>> + '_> {
+        let v: Vec<&dyn Index<PlayerRating>> = vec![&self.rating];
+        Box::new(v.into_iter())
+    }
+}
+
+// (player, Elo rating) indexed by rating for the top ratings query
+pub fn ratings<'a>() -> IndexedMap<&'a Addr, PlayerRating, RatingIndexes<'a>> {
+    let indexes = RatingIndexes {
+        rating: MultiIndex::new(
+            |_pk, r: &PlayerRating| r.rating.atomics().u128(),
+            "ratings",
+            "ratings__rating",
+        ),
+    };
+    IndexedMap::new("ratings", indexes)
+}
 
 #[cw_serde]
 pub struct GameMetadata {
     pub base_url: String,
     pub image_url: String,
     pub token_contract: Addr,
+    pub identity_contract: Option<Addr>, // registry used to resolve players' telegram IDs, if any
+    pub attestation_pubkey: Option<Binary>, // secp256k1 key of the bot attesting telegram IDs, if any
 }
 
 #[cw_serde]
-pub struct Game {
+pub struct Game<Ext = Empty> {
     pub id: u64,
     pub players: Vec<(Addr, String)>, // (player, telegram ID)
     pub rounds: Vec<GameRound>,
     pub current_round: u8,
     pub status: GameStatus,
-    pub config: GameConfig,
+    pub config: GameConfig<Ext>,
     pub creator: Addr,
     pub total_escrow: Uint128, // Total escrowed funds for this game
     pub player_escrow: Vec<(Addr, Uint128)>, // (player, escrowed funds)
+    #[serde(deserialize_with = "deserialize_scores")]
     pub scores: HashMap<Addr, Uint128>, // (player, score)
+    pub slashed_bonds: Vec<(u8, Addr, Uint128)>, // (round, player, slashed bond)
+}
+
+/// Reads the scores of a game with string keys, serde-json-wasm cannot deserialize map keys
+/// into an `Addr`
+fn deserialize_scores<'de, D>(deserializer: D) -> Result<HashMap<Addr, Uint128>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let scores = HashMap::<String, Uint128>::deserialize(deserializer)?;
+    Ok(scores
+        .into_iter()
+        .map(|(player, score)| (Addr::unchecked(player), score))
+        .collect())
 }
 
 #[cw_serde]
 pub struct GameRound {
     pub id: u8,
-    pub expires_at: Option<u64>, // specific block at which the round expires, null if no expire
+    pub commit_expires_at: Option<Expiration>, // end of the commit window, null if no expire
+    pub reveal_expires_at: Option<Expiration>, // end of the reveal window, set once the round is committed
     pub commits: Vec<(Addr, String, Option<Uint128>)>, // (player, committed value, committed amout)
     pub reveals: Vec<(Addr, String)>, // (player, revealed value)
+    pub forfeited_by: Vec<Addr>, // players who did not reveal and forfeited the round to the others
     pub status: GameRoundStatus,
 }
 
 #[cw_serde]
 pub enum GameRoundStatus {
-    Pending,
-    Committed,
-    Revealed,
-    Ended,
+    Pending,   // Accepting commits
+    Committed, // Commit window closed, accepting reveals
+    Revealed,  // Reveal window closed, waiting to be scored
+    Ended,     // Round closed, the next round has started
+    Voided,    // Round closed without being scored, see `AbsencePolicy`
+}
+
+/// What happens to players who did not reveal by the time a round closes
+#[cw_serde]
+pub enum AbsencePolicy {
+    Forfeit,                       // the players who revealed win the round
+    DefaultMove { value: String }, // absent players are recorded as having revealed this value
+    Void,                          // the round is not scored
 }
 
 #[cw_serde]
@@ -60,19 +122,73 @@ pub enum GameStatus {
 }
 
 #[cw_serde]
-pub struct GameConfig {
+pub struct GameConfig<Ext = Empty> {
     pub game_joining_fee: Option<Uint128>,
     pub min_deposit: Uint128,
     pub max_players: Option<u8>,
     pub min_players: u8,
-    pub round_expiry_duration: Option<u64>, //in Blocks
+    pub commit_duration: Option<Duration>, // in blocks or seconds, from the start of the round
+    pub reveal_duration: Option<Duration>, // in blocks or seconds, from the end of the commit phase
     pub max_rounds: u8,
     pub round_reward_multiplier: Option<u64>,
     pub has_turns: bool,
     pub skip_reveal: bool,
+    pub absence_policy: AbsencePolicy,
+    pub commit_bond: Option<Uint128>, // pulled at commit, returned on reveal and slashed otherwise
+    pub bond_treasury: Option<Addr>, // receives slashed bonds, split between revealing players if not set
+    pub extension: Ext, // game specific parameters, see `GameLifecycle::Extension`
 }
 
 #[cw_serde]
 pub struct Leaderboard {
     pub leaderboard: Vec<(Addr, String, Uint128)>,
 }
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PlayerStats {
+    pub games_played: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+    pub total_rewards: Uint128,
+    pub current_win_streak: u64,
+    pub best_win_streak: u64,
+    pub last_played_block: u64,
+}
+
+#[cw_serde]
+pub enum GameOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+#[cw_serde]
+pub struct RatingConfig {
+    pub initial_rating: u64,
+    pub k_factor: u64,
+    pub provisional_k_factor: u64, // K-factor used while a player has fewer than `provisional_games` rated games
+    pub provisional_games: u64,
+}
+
+#[cw_serde]
+pub struct PlayerRating {
+    pub player: Addr,
+    pub rating: Decimal,
+    pub games_rated: u64,
+}
+
+#[cw_serde]
+pub struct TelegramAttestation {
+    pub expires_at: u64, // unix timestamp in seconds
+    pub signature: Binary, // signature over `TelegramAttestation::digest`
+}
+
+#[cw_serde]
+pub struct Delegation {
+    pub game_id: Option<u64>, // game the delegate can act in, any game if not set
+    pub expires_at: Expiration,
+    pub max_actions: u32,
+    pub actions_used: u32,
+}
@@ -0,0 +1,194 @@
+//! Multi-test fixtures shared by the tests of the game contracts, enabled by the `test-utils`
+//! feature. The helpers play game 0 and commit with a nonce of 123.
+use std::fmt::Debug;
+
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_multi_test::error::{AnyError, AnyResult};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::state::GameConfig;
+use crate::ContractError;
+
+const NONCE: u64 = 123;
+
+/// Serializes like the `Lifecycle` variant `#[lifecycle_execute]` adds to a game's `ExecuteMsg`
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+enum GameExecuteMsg<Ext> {
+    Lifecycle(ExecuteMsg<Ext>),
+}
+
+/// Instantiates the P2E token contract, the owner holds the whole initial supply
+pub fn instantiate_p2e(app: &mut App) -> Addr {
+    let owner = app.api().addr_make("owner");
+    let p2e_code_id = app.store_code(Box::new(ContractWrapper::new(
+        cw_p2e::contract::execute,
+        cw_p2e::contract::instantiate,
+        cw_p2e::contract::query,
+    )));
+    let init_msg = cw_p2e::msg::InstantiateMsg {
+        name: "glitch".to_string(),
+        symbol: "glitch".to_string(),
+        decimals: 18,
+        initial_supply: Uint128::new(1_000_000),
+        initial_balances: vec![cw20::Cw20Coin {
+            address: owner.to_string(),
+            amount: Uint128::new(1_000_000),
+        }],
+        reward_per_block: Uint128::new(1000),
+        mint: None,
+        marketing: None,
+    };
+    app.instantiate_contract(p2e_code_id, owner, &init_msg, &[], "p2e", None).unwrap()
+}
+
+/// Stores and instantiates a game contract, owned by the same account as the P2E token
+pub fn instantiate_game<M: Serialize + Debug>(
+    app: &mut App,
+    game: Box<dyn Contract<Empty>>,
+    init_msg: &M,
+) -> AnyResult<Addr> {
+    let owner = app.api().addr_make("owner");
+    let game_code_id = app.store_code(game);
+    app.instantiate_contract(game_code_id, owner, init_msg, &[], "game", None)
+}
+
+/// Instantiate message of a game that takes the lifecycle's parameters only
+pub fn instantiate_msg(token_contract: Addr) -> InstantiateMsg {
+    InstantiateMsg {
+        base_url: "https://example.com".to_string(),
+        image_url: "https://example.com/image.png".to_string(),
+        token_contract,
+        rating_config: None,
+        identity_contract: None,
+        attestation_pubkey: None,
+    }
+}
+
+/// Instantiates the P2E token and the game, built from the token's address, and allows the game
+/// to mint rewards. Returns the addresses of the token and the game
+pub fn setup_contracts<M: Serialize + Debug>(
+    app: &mut App,
+    game: Box<dyn Contract<Empty>>,
+    init_msg: impl FnOnce(Addr) -> M,
+) -> (Addr, Addr) {
+    let owner = app.api().addr_make("owner");
+    let p2e_contract = instantiate_p2e(app);
+    let game_contract = instantiate_game(app, game, &init_msg(p2e_contract.clone())).unwrap();
+
+    let msg = cw_p2e::msg::ExecuteMsg::AuthorizeRewardsIssuer {
+        address: game_contract.to_string(),
+    };
+    app.execute_contract(owner, p2e_contract.clone(), &msg, &[]).unwrap();
+
+    (p2e_contract, game_contract)
+}
+
+/// Config of a game between a fixed number of players
+pub fn game_config<Ext: Default>(players: u8, max_rounds: u8, extension: Ext) -> GameConfig<Ext> {
+    GameConfig {
+        min_players: players,
+        max_players: Some(players),
+        max_rounds,
+        extension,
+        ..Default::default()
+    }
+}
+
+/// Executes a lifecycle message on a game contract. Games are created with `create_game`, which
+/// carries the game's config extension
+pub fn execute_lifecycle(
+    app: &mut App,
+    contract: &Addr,
+    sender: &Addr,
+    msg: ExecuteMsg,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(sender.clone(), contract.clone(), &GameExecuteMsg::Lifecycle(msg), &[])
+}
+
+pub fn create_game<Ext: Serialize + Debug>(
+    app: &mut App,
+    contract: &Addr,
+    creator: &Addr,
+    config: GameConfig<Ext>,
+) -> AnyResult<AppResponse> {
+    let msg = GameExecuteMsg::Lifecycle(ExecuteMsg::CreateGame { config });
+    app.execute_contract(creator.clone(), contract.clone(), &msg, &[])
+}
+
+/// Creates game 0, has every player join it and starts it. The first player creates the game
+pub fn start_game<Ext: Serialize + Debug>(
+    app: &mut App,
+    contract: &Addr,
+    players: &[&Addr],
+    config: GameConfig<Ext>,
+) {
+    create_game(app, contract, players[0], config).unwrap();
+    for player in players {
+        let msg = ExecuteMsg::JoinGame {
+            game_id: 0,
+            telegram_id: player.to_string(),
+            attestation: None,
+        };
+        execute_lifecycle(app, contract, player, msg).unwrap();
+    }
+    execute_lifecycle(app, contract, players[0], ExecuteMsg::StartGame { game_id: 0 }).unwrap();
+}
+
+pub fn commit(app: &mut App, contract: &Addr, player: &Addr, value: &str) -> AnyResult<()> {
+    let mut hasher = Sha256::new();
+    hasher.update(value.as_bytes());
+    hasher.update(NONCE.to_be_bytes());
+    let msg = ExecuteMsg::CommitRound {
+        game_id: 0,
+        value: hex::encode(hasher.finalize()),
+        amount: None,
+    };
+    execute_lifecycle(app, contract, player, msg).map(|_| ())
+}
+
+pub fn reveal(app: &mut App, contract: &Addr, player: &Addr, value: &str) -> AnyResult<()> {
+    let msg = ExecuteMsg::RevealRound {
+        game_id: 0,
+        value: value.to_string(),
+        nonce: NONCE,
+    };
+    execute_lifecycle(app, contract, player, msg).map(|_| ())
+}
+
+/// Commits every move, then reveals them in the same order
+pub fn play_round(app: &mut App, contract: &Addr, moves: &[(&Addr, &str)]) {
+    for (player, value) in moves {
+        commit(app, contract, player, value).unwrap();
+    }
+    for (player, value) in moves {
+        reveal(app, contract, player, value).unwrap();
+    }
+}
+
+pub fn play_turn(app: &mut App, contract: &Addr, player: &Addr, value: &str) -> AnyResult<()> {
+    let msg = ExecuteMsg::PlayTurn {
+        game_id: 0,
+        value: value.to_string(),
+    };
+    execute_lifecycle(app, contract, player, msg).map(|_| ())
+}
+
+pub fn balance_of(app: &App, p2e_contract: &Addr, player: &Addr) -> Uint128 {
+    let balance: cw20::BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            p2e_contract.clone(),
+            &cw_p2e::msg::QueryMsg::Balance { address: player.to_string() },
+        )
+        .unwrap();
+    balance.balance
+}
+
+/// Games wrap the lifecycle errors without changing their message
+pub fn assert_lifecycle_error(err: AnyError, expected: ContractError) {
+    assert_eq!(err.root_cause().to_string(), expected.to_string());
+}
@@ -238,6 +238,7 @@ const repl = program()
                 command('play-round')
                     .description('Play a round in a game')
                     .option('choice', { prompt: 'The choice to play', type: 'string' })
+                    .option('player', { description: 'The player to play for, if they granted you a delegation', type: 'string' })
                     .action(async (args) => {
                         if (!currentGame?.getGameId() && currentGame?.getGameId() !== 0) {
                             throw new Error('You must specifiy a game ID or join / create a game before you can get details')
@@ -248,19 +249,48 @@ const repl = program()
                             throw new Error(`Invalid choice: ${args['choice']}. Valid choices are: ${choices.join(', ')}`)
                         }
 
-                        const result = await currentGame?.commitRound(args['choice']!)
+                        const result = await currentGame?.commitRound(args['choice']!, args['player'])
                         console.dir(result, { depth: null })
                     }),
             )
             .add(
                 command('reveal-round')
                     .description('Reveal a round in a game')
+                    .option('player', { description: 'The player to reveal for, if they granted you a delegation', type: 'string' })
                     .action(async (args) => {
                         if (!currentGame?.getGameId() && currentGame?.getGameId() !== 0) {
                             throw new Error('You must specifiy a game ID or join / create a game before you can get details')
                         }
 
-                        const result = await currentGame?.revealRound()
+                        const result = await currentGame?.revealRound(args['player'])
+                        console.dir(result, { depth: null })
+                    }),
+            )
+            .add(
+                command('grant-delegate')
+                    .description('Let another address commit and reveal for you in the current game')
+                    .option('delegate', { prompt: 'The address to delegate to', type: 'string' })
+                    .option('max-actions', { prompt: 'How many commits and reveals the delegate may send', type: 'number' })
+                    .option('expires-at', { prompt: 'The block height the delegation expires at', type: 'number' })
+                    .action(async (args) => {
+                        if (!currentGame?.getGameId() && currentGame?.getGameId() !== 0) {
+                            throw new Error('You must specifiy a game ID or join / create a game before you can get details')
+                        }
+
+                        const result = await currentGame?.grantDelegate(args['delegate']!, args['max-actions']!, args['expires-at']!)
+                        console.dir(result, { depth: null })
+                    }),
+            )
+            .add(
+                command('revoke-delegate')
+                    .description('Revoke a delegation you granted')
+                    .option('delegate', { prompt: 'The address to revoke', type: 'string' })
+                    .action(async (args) => {
+                        if (!currentGame?.getGameId() && currentGame?.getGameId() !== 0) {
+                            throw new Error('You must specifiy a game ID or join / create a game before you can get details')
+                        }
+
+                        const result = await currentGame?.revokeDelegate(args['delegate']!)
                         console.dir(result, { depth: null })
                     }),
             )
@@ -29,21 +29,24 @@ export default class Dilemma {
         await this.setGameClient()
     }
 
-    async createGame(config: Partial<DilemmaTypes.GameConfig> = {}) {
+    async createGame(config: Partial<DilemmaTypes.GameConfigForDilemmaConfig> = {}) {
         if (!this.gameClient) {
             await this.setGameClient()
         }
 
-        const gameConfig: DilemmaTypes.GameConfig = {
+        const gameConfig: DilemmaTypes.GameConfigForDilemmaConfig = {
+            absence_policy: 'forfeit',
             game_joining_fee: null,
             has_turns: true,
             min_players: 2,
             max_players: 2,
             max_rounds: 1,
             min_deposit: '0',
-            round_expiry_duration: 1000,
+            commit_duration: { height: 1000 },
+            reveal_duration: { height: 1000 },
             round_reward_multiplier: 1,
             skip_reveal: false,
+            extension: { payoffs: null },
             ...config,
         }
 
@@ -20,10 +20,10 @@ export enum GameType {
 }
 
 export type GameConfig = {
-    [GameType.DILEMMA]: DilemmaTypes.GameConfig,
-    [GameType.TRADE_GAINS]: TradeGainsTypes.GameConfig,
-    [GameType.ROCK_PAPER_SCISSORS]: RockPaperScissorsTypes.GameConfig,
-    [GameType.ASTEROID]: AsteroidTypes.GameConfig,
+    [GameType.DILEMMA]: DilemmaTypes.GameConfigForDilemmaConfig,
+    [GameType.TRADE_GAINS]: TradeGainsTypes.GameConfigForTradeConfig,
+    [GameType.ROCK_PAPER_SCISSORS]: RockPaperScissorsTypes.GameConfigForRockPaperScissorsConfig,
+    [GameType.ASTEROID]: AsteroidTypes.GameConfigForAsteroidConfig,
 }
 
 export default class Game {
@@ -77,6 +77,19 @@ export default class Game {
         }
     }
 
+    getDefaultExtension(): GameConfig[GameType]['extension'] {
+        switch (this.gameType) {
+            case GameType.DILEMMA:
+                return { payoffs: null }
+            case GameType.TRADE_GAINS:
+                return { pie: 10, multiplier: '10', overflow: 'zero', mode: 'demand' }
+            case GameType.ROCK_PAPER_SCISSORS:
+                return { variant: 'classic' }
+            case GameType.ASTEROID:
+                return { handicaps: [] }
+        }
+    }
+
     async connect(gameType: GameType, gameId: number) {
         this.gameType = gameType
         this.gameId = Number(gameId)
@@ -88,18 +101,21 @@ export default class Game {
             await this.setGameClient()
         }
 
-        const gameConfig: GameConfig[T] = {
+        const gameConfig = {
+            absence_policy: 'forfeit',
             game_joining_fee: null,
             has_turns: true,
             min_players: 2,
             max_players: 2,
             max_rounds: 1,
             min_deposit: '0',
-            round_expiry_duration: 1000,
+            commit_duration: { height: 1000 },
+            reveal_duration: { height: 1000 },
             round_reward_multiplier: 1,
             skip_reveal: false,
+            extension: this.getDefaultExtension(),
             ...config,
-        }
+        } as GameConfig[T]
 
         const result = await this.gameClient.client.execute(this.gameClient.sender, this.gameContractAddress, {
             lifecycle: {
@@ -160,7 +176,7 @@ export default class Game {
         return result
     }
 
-    async commitRound(choice: string) {
+    async commitRound(choice: string, player?: string) {
         if (!this.gameClient) {
             await this.setGameClient()
         }
@@ -180,7 +196,13 @@ export default class Game {
             .join('')
 
         const result = await this.gameClient.client.execute(this.gameClient.sender, this.gameContractAddress, {
-            lifecycle: {
+            lifecycle: player ? {
+                commit_round_as_delegate: {
+                    game_id: this.gameId!,
+                    player,
+                    value: hash,
+                }
+            } : {
                 commit_round: {
                     game_id: this.gameId!,
                     value: hash,
@@ -194,13 +216,20 @@ export default class Game {
         return result
     }
 
-    async revealRound() {
+    async revealRound(player?: string) {
         if (!this.gameClient) {
             await this.setGameClient()
         }
 
         const result = await this.gameClient.client.execute(this.gameClient.sender, this.gameContractAddress, {
-            lifecycle: {
+            lifecycle: player ? {
+                reveal_round_as_delegate: {
+                    game_id: this.gameId!,
+                    player,
+                    value: this.choice!,
+                    nonce: this.nonce,
+                }
+            } : {
                 reveal_round: {
                     game_id: this.gameId!,
                     value: this.choice!,
@@ -215,6 +244,47 @@ export default class Game {
         return result
     }
 
+    async grantDelegate(delegate: string, maxActions: number, expiresAtHeight: number) {
+        if (!this.gameClient) {
+            await this.setGameClient()
+        }
+
+        const result = await this.gameClient.client.execute(this.gameClient.sender, this.gameContractAddress, {
+            lifecycle: {
+                grant_delegate: {
+                    delegate,
+                    game_id: this.gameId,
+                    expires_at: { at_height: expiresAtHeight },
+                    max_actions: maxActions,
+                }
+            }
+        }, {
+            amount: [{ denom: 'uxion', amount: '0' }],
+            gas: '200000',
+        })
+
+        return result
+    }
+
+    async revokeDelegate(delegate: string) {
+        if (!this.gameClient) {
+            await this.setGameClient()
+        }
+
+        const result = await this.gameClient.client.execute(this.gameClient.sender, this.gameContractAddress, {
+            lifecycle: {
+                revoke_delegate: {
+                    delegate,
+                }
+            }
+        }, {
+            amount: [{ denom: 'uxion', amount: '0' }],
+            gas: '200000',
+        })
+
+        return result
+    }
+
     async endGame() {
         if (!this.gameClient) {
             await this.setGameClient()